@@ -0,0 +1,70 @@
+//! Generates the solution registry: every `src/bin/YYYY/DD.rs` is compiled into the `aoc-runner` binary as a module,
+//! so that commands like `all` can call solutions in-process. The library does not include solutions,
+//! a day that does not compile only breaks `aoc-runner`.
+use std::{env, fmt::Write, fs, path::Path};
+
+/// Parses a file name stem as number in the given range.
+fn parse_in_range(name: &str, range: std::ops::RangeInclusive<u16>) -> Option<u16> {
+    let value = name.parse().ok()?;
+    range.contains(&value).then_some(value)
+}
+
+fn main() {
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let bin_dir = Path::new(&manifest_dir).join("src").join("bin");
+
+    // re-run whenever a day is added, removed or changed.
+    println!("cargo:rerun-if-changed={}", bin_dir.display());
+
+    let mut puzzles: Vec<(u16, u16, String)> = vec![];
+
+    for year_entry in fs::read_dir(&bin_dir).unwrap().flatten() {
+        let year_path = year_entry.path();
+        let Some(year) = year_path
+            .file_name()
+            .and_then(|name| parse_in_range(name.to_str()?, 2015..=9999))
+        else {
+            continue;
+        };
+        if !year_path.is_dir() {
+            continue;
+        }
+
+        for day_entry in fs::read_dir(&year_path).unwrap().flatten() {
+            let path = day_entry.path();
+            if path.extension().is_none_or(|ext| ext != "rs") {
+                continue;
+            }
+            let Some(day) = path
+                .file_stem()
+                .and_then(|stem| parse_in_range(stem.to_str()?, 1..=25))
+            else {
+                continue;
+            };
+            puzzles.push((year, day, path.to_string_lossy().replace('\\', "/")));
+        }
+    }
+
+    puzzles.sort_unstable();
+
+    let mut out = String::new();
+
+    for (year, day, path) in &puzzles {
+        writeln!(out, "#[allow(warnings, clippy::all, clippy::pedantic)]").unwrap();
+        writeln!(out, "#[path = {path:?}]").unwrap();
+        writeln!(out, "mod y{year}_day{day:02};").unwrap();
+    }
+
+    writeln!(
+        out,
+        "static SOLUTIONS: &[advent_of_code::template::registry::Solution] = &["
+    )
+    .unwrap();
+    for (year, day, _) in &puzzles {
+        writeln!(out, "    y{year}_day{day:02}::SOLUTION,").unwrap();
+    }
+    writeln!(out, "];").unwrap();
+
+    let out_path = Path::new(&env::var("OUT_DIR").unwrap()).join("solutions.rs");
+    fs::write(out_path, out).unwrap();
+}
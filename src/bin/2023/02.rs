@@ -0,0 +1,87 @@
+use regex::Regex;
+advent_of_code::solution!(2023, 2, parse);
+
+#[derive(Debug)]
+pub struct Game {
+    id: u32,
+    red: u32,
+    green: u32,
+    blue: u32,
+}
+
+pub fn parse(input: &str) -> Vec<Game> {
+    let game_id: Regex = Regex::new(r"(Game )(\d+)").unwrap();
+    let red: Regex = Regex::new(r"(\d+)( red)").unwrap();
+    let green: Regex = Regex::new(r"(\d+)( green)").unwrap();
+    let blue: Regex = Regex::new(r"(\d+)( blue)").unwrap();
+
+    input
+        .lines()
+        .map(|line| Game {
+            id: game_id
+                .captures(line)
+                .unwrap()
+                .get(2)
+                .unwrap()
+                .as_str()
+                .parse::<u32>()
+                .unwrap(),
+            red: red
+                .captures_iter(line)
+                .map(|c| c.get(1).unwrap().as_str().parse::<u32>().unwrap())
+                .max()
+                .unwrap(),
+            green: green
+                .captures_iter(line)
+                .map(|c| c.get(1).unwrap().as_str().parse::<u32>().unwrap())
+                .max()
+                .unwrap(),
+            blue: blue
+                .captures_iter(line)
+                .map(|c| c.get(1).unwrap().as_str().parse::<u32>().unwrap())
+                .max()
+                .unwrap(),
+        })
+        .collect()
+}
+
+pub fn part_one(games: &[Game]) -> Option<u32> {
+    const MAX_RED: u32 = 12;
+    const MAX_GREEN: u32 = 13;
+    const MAX_BLUE: u32 = 14;
+
+    Some(
+        games
+            .iter()
+            .filter(|game| game.red <= MAX_RED && game.green <= MAX_GREEN && game.blue <= MAX_BLUE)
+            .fold(0, |acc, game| acc + game.id),
+    )
+}
+
+pub fn part_two(games: &[Game]) -> Option<u32> {
+    Some(
+        games
+            .iter()
+            .map(|game| game.red * game.green * game.blue)
+            .fold(0, |acc, game_power| acc + game_power),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = parse(&advent_of_code::template::read_file("examples", PUZZLE));
+        let result = part_one(&input);
+        assert_eq!(result, Some(8));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = parse(&advent_of_code::template::read_file("examples", PUZZLE));
+        let result = part_two(&input);
+        assert_eq!(result, Some(2286));
+    }
+}
@@ -1,7 +1,7 @@
 use std::u64;
 
 use itertools::Itertools;
-advent_of_code::solution!(5);
+advent_of_code::solution!(2023, 5);
 
 #[derive(Debug)]
 struct Range {
@@ -135,13 +135,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(35));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(46));
     }
 }
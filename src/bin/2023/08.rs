@@ -2,7 +2,7 @@ use itertools::{FoldWhile, Itertools};
 use num_integer::lcm;
 use std::collections::HashMap;
 
-advent_of_code::solution!(8);
+advent_of_code::solution!(2023, 8);
 
 #[derive(Debug)]
 struct Node<'a> {
@@ -104,13 +104,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(6));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(6));
     }
 }
@@ -1,7 +1,7 @@
 use std::{collections::HashMap, usize};
 
 use itertools::Itertools;
-advent_of_code::solution!(10);
+advent_of_code::solution!(2023, 10);
 
 fn get_dir(char: &u8) -> Vec<(i8, i8)> {
     match char {
@@ -159,13 +159,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(80));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(10));
     }
 }
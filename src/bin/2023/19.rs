@@ -2,7 +2,7 @@ use std::collections::{HashMap, HashSet};
 
 use itertools::Itertools;
 
-advent_of_code::solution!(19);
+advent_of_code::solution!(2023, 19, parse);
 
 #[derive(Debug, Clone, Copy)]
 enum RuleResult<'a> {
@@ -258,7 +258,12 @@ impl Part {
     }
 }
 
-fn parse(input: &str) -> (Rules, Vec<Part>) {
+pub struct Input<'a> {
+    rules: Rules<'a>,
+    parts: Vec<Part>,
+}
+
+pub fn parse(input: &str) -> Input<'_> {
     let (rules, parts) = input.split_once("\n\n").unwrap();
 
     let rules = Rules {
@@ -324,11 +329,11 @@ fn parse(input: &str) -> (Rules, Vec<Part>) {
         })
         .collect_vec();
 
-    (rules, parts)
+    Input { rules, parts }
 }
 
-pub fn part_one(input: &str) -> Option<usize> {
-    let (rules, parts) = parse(input);
+pub fn part_one(input: &Input) -> Option<usize> {
+    let Input { rules, parts } = input;
 
     Some(
         parts
@@ -339,8 +344,8 @@ pub fn part_one(input: &str) -> Option<usize> {
     )
 }
 
-pub fn part_two(input: &str) -> Option<usize> {
-    let (rules, _) = parse(input);
+pub fn part_two(input: &Input) -> Option<usize> {
+    let rules = &input.rules;
     let start_part = PartRange::new();
     let results = rules.apply_range(&"in", start_part);
 
@@ -368,13 +373,17 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let file = advent_of_code::template::read_file("examples", PUZZLE);
+        let input = parse(&file);
+        let result = part_one(&input);
         assert_eq!(result, Some(19114));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let file = advent_of_code::template::read_file("examples", PUZZLE);
+        let input = parse(&file);
+        let result = part_two(&input);
         assert_eq!(result, Some(167409079868000));
     }
 }
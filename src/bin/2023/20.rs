@@ -2,7 +2,7 @@ use std::collections::{HashMap, VecDeque};
 
 use itertools::Itertools;
 
-advent_of_code::solution!(20);
+advent_of_code::solution!(2023, 20);
 
 #[derive(Debug)]
 enum ModuleType {
@@ -232,13 +232,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(4250 * 2750));
     }
 
     #[test]
     fn test_part_two() {
-        let result = Some(0); //part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = Some(0); //part_two(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(0));
     }
 }
@@ -5,7 +5,7 @@ use std::{
 
 use itertools::Itertools;
 
-advent_of_code::solution!(21);
+advent_of_code::solution!(2023, 21);
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 struct Coords {
@@ -200,13 +200,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(42));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(6536));
     }
 }
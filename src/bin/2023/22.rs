@@ -3,7 +3,7 @@ use std::{collections::HashMap, ops::RangeBounds};
 use itertools::Itertools;
 use regex::Regex;
 
-advent_of_code::solution!(22);
+advent_of_code::solution!(2023, 22);
 
 #[derive(Debug, Eq, PartialEq, Hash, Clone, Copy)]
 struct Coord {
@@ -146,13 +146,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(5));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(7));
     }
 }
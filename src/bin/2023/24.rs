@@ -1,7 +1,7 @@
 use itertools::Itertools;
 use regex::Regex;
 
-advent_of_code::solution!(24);
+advent_of_code::solution!(2023, 24);
 
 #[derive(Debug, Clone, Copy)]
 struct Hailstorm {
@@ -96,18 +96,16 @@ pub fn part_one(input: &str) -> Option<usize> {
     )
 }
 
-pub fn part_two(input: &str) -> Option<u32> {
-    let hailstorms = parse(input);
-    let hailstorms = hailstorms.iter().take(3).collect_vec();
-    println!("{:?}", hailstorms);
-    println!("(79 u v + 276481733510955 u - 359781776524153 v)/(u - v) = 420851642592931");
-    println!("(5 (u (29 v + 54173413157932) - 62541132055815 v))/(u - v) = 273305746686315");
-    println!("(3 u (5 v + 91256287537271) - 236728636905923 v)/(u - v) = 176221626745613");
-    println!("(-44 u - 35 v + 83300043013198)/(u - v) = -261");
-    println!("-(5 (25 u + 4 v - 8367718897883))/(u - v) = 15");
-    println!("(18 u - 33 v - 37040225705890)/(u - v) = 233");
-    println!("With t=637228617556,v=487736179331,u=281427954234");
-    println!("{}", "https://matrixcalc.org/slu.html#solve-using-Gaussian-elimination(%7B%7B1,0,0,u,0,0,0,0,0,-44*u+359781776524153%7D,%7B0,1,0,0,u,0,0,0,0,-125*u+312705660279075%7D,%7B0,0,1,0,0,u,0,0,0,18*u+236728636905923%7D,%7B1,0,0,v,0,0,0,0,0,35*v+276481733510955%7D,%7B0,1,0,0,v,0,0,0,0,20*v+270867065789660%7D,%7B0,0,1,0,0,v,0,0,0,33*v+273768862611813%7D,%7B1,0,0,t,0,0,0,0,0,102*t+189537654420103%7D,%7B0,1,0,0,t,0,0,0,0,-15*t+292422605212995%7D,%7B0,0,1,0,0,t,0,0,0,-14*t+333617095281945%7D%7D)");
+/// Solved by hand: the first three hailstorms give a system of equations in the collision times `t`, `u` and `v`.
+pub fn part_two(_input: &str) -> Option<u32> {
+    // (79 u v + 276481733510955 u - 359781776524153 v)/(u - v) = 420851642592931
+    // (5 (u (29 v + 54173413157932) - 62541132055815 v))/(u - v) = 273305746686315
+    // (3 u (5 v + 91256287537271) - 236728636905923 v)/(u - v) = 176221626745613
+    // (-44 u - 35 v + 83300043013198)/(u - v) = -261
+    // -(5 (25 u + 4 v - 8367718897883))/(u - v) = 15
+    // (18 u - 33 v - 37040225705890)/(u - v) = 233
+    // With t=637228617556,v=487736179331,u=281427954234
+    // https://matrixcalc.org/slu.html#solve-using-Gaussian-elimination(%7B%7B1,0,0,u,0,0,0,0,0,-44*u+359781776524153%7D,%7B0,1,0,0,u,0,0,0,0,-125*u+312705660279075%7D,%7B0,0,1,0,0,u,0,0,0,18*u+236728636905923%7D,%7B1,0,0,v,0,0,0,0,0,35*v+276481733510955%7D,%7B0,1,0,0,v,0,0,0,0,20*v+270867065789660%7D,%7B0,0,1,0,0,v,0,0,0,33*v+273768862611813%7D,%7B1,0,0,t,0,0,0,0,0,102*t+189537654420103%7D,%7B0,1,0,0,t,0,0,0,0,-15*t+292422605212995%7D,%7B0,0,1,0,0,t,0,0,0,-14*t+333617095281945%7D%7D)
     Some(0)
 }
 
@@ -117,13 +115,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(0));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(0));
     }
 }
@@ -0,0 +1,55 @@
+use advent_of_code::graph::{min_cut, Graph, Interner};
+
+advent_of_code::solution!(2023, 25);
+
+fn parse(input: &str) -> Graph {
+    let mut components = Interner::default();
+    let mut wires = vec![];
+
+    for line in input.lines() {
+        let Some((from, to)) = line.split_once(": ") else {
+            continue;
+        };
+        let from = components.intern(from);
+        for to in to.split_whitespace() {
+            wires.push((from, components.intern(to)));
+        }
+    }
+
+    let mut graph = Graph::new(components.len());
+    for (from, to) in wires {
+        graph.add_undirected_edge(from, to, 1);
+    }
+    graph
+}
+
+pub fn part_one(input: &str) -> Option<usize> {
+    let cut = min_cut(&parse(input))?;
+    // the puzzle asks to disconnect exactly three wires.
+    if cut.edges.len() != 3 {
+        return None;
+    }
+    let (a, b) = cut.sizes();
+    Some(a * b)
+}
+
+pub fn part_two(input: &str) -> Option<u32> {
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&advent_of_code::template::read_file("examples", PUZZLE));
+        assert_eq!(result, Some(54));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&advent_of_code::template::read_file("examples", PUZZLE));
+        assert_eq!(result, None);
+    }
+}
@@ -1,3 +1,4 @@
+use serde::{Deserialize, Serialize};
 use std::error::Error;
 use std::fmt::Display;
 use std::str::FromStr;
@@ -12,7 +13,11 @@ use std::str::FromStr;
 /// let day = Day::new(8).unwrap();
 /// assert_eq!(day.to_string(), "08")
 /// ```
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+///
+/// # Serialization
+/// This value serializes as a plain integer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(try_from = "u8", into = "u8")]
 pub struct Day(u8);
 
 impl Day {
@@ -55,6 +60,20 @@ impl PartialOrd<u8> for Day {
     }
 }
 
+impl From<Day> for u8 {
+    fn from(day: Day) -> Self {
+        day.0
+    }
+}
+
+impl TryFrom<u8> for Day {
+    type Error = DayFromStrError;
+
+    fn try_from(day: u8) -> Result<Self, Self::Error> {
+        Self::new(day).ok_or(DayFromStrError)
+    }
+}
+
 /* -------------------------------------------------------------------------- */
 
 impl FromStr for Day {
@@ -114,6 +133,69 @@ impl Iterator for AllDays {
 
 /* -------------------------------------------------------------------------- */
 
+/// A set of days, parsed from a comma-separated list of days and inclusive day ranges.
+///
+/// ```
+/// # use advent_of_code::{Day, DaySelection};
+/// let days: DaySelection = "5,17-19".parse().unwrap();
+/// assert!(days.contains(Day::new(18).unwrap()));
+/// assert!(!days.contains(Day::new(6).unwrap()));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DaySelection(Vec<Day>);
+
+impl DaySelection {
+    pub fn contains(&self, day: Day) -> bool {
+        self.0.binary_search(&day).is_ok()
+    }
+
+    /// An iterator over the selected days in ascending order.
+    pub fn days(&self) -> impl Iterator<Item = Day> + '_ {
+        self.0.iter().copied()
+    }
+}
+
+impl FromStr for DaySelection {
+    type Err = DaySelectionFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut days = vec![];
+
+        for item in s.split(',').map(str::trim) {
+            let (start, end) = match item.split_once('-') {
+                Some((start, end)) => (start.trim(), end.trim()),
+                None => (item, item),
+            };
+
+            let start: Day = start.parse().map_err(|_| DaySelectionFromStrError)?;
+            let end: Day = end.parse().map_err(|_| DaySelectionFromStrError)?;
+            if start > end {
+                return Err(DaySelectionFromStrError);
+            }
+
+            days.extend((start.0..=end.0).map(Day));
+        }
+
+        days.sort_unstable();
+        days.dedup();
+        Ok(Self(days))
+    }
+}
+
+/// An error which can be returned when parsing a [`DaySelection`].
+#[derive(Debug)]
+pub struct DaySelectionFromStrError;
+
+impl Error for DaySelectionFromStrError {}
+
+impl Display for DaySelectionFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("expecting a comma-separated list of days and ranges, e.g. `5,17-23`")
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
 /// Creates a [`Day`] value in a const context.
 #[macro_export]
 macro_rules! day {
@@ -134,7 +216,7 @@ macro_rules! day {
 
 #[cfg(feature = "test_lib")]
 mod tests {
-    use super::{all_days, Day};
+    use super::{all_days, Day, DaySelection};
 
     #[test]
     fn all_days_iterator() {
@@ -167,6 +249,23 @@ mod tests {
         assert_eq!(iter.next(), Some(Day(25)));
         assert_eq!(iter.next(), None);
     }
+
+    #[test]
+    fn parses_day_selection() {
+        let days: DaySelection = "5,17-19, 3 ,18".parse().unwrap();
+        assert_eq!(days.days().collect::<Vec<_>>(), [3, 5, 17, 18, 19].map(Day));
+        assert!(days.contains(Day(17)));
+        assert!(!days.contains(Day(20)));
+    }
+
+    #[test]
+    fn rejects_invalid_day_selection() {
+        assert!("".parse::<DaySelection>().is_err());
+        assert!("0".parse::<DaySelection>().is_err());
+        assert!("5-26".parse::<DaySelection>().is_err());
+        assert!("7-3".parse::<DaySelection>().is_err());
+        assert!("1,,2".parse::<DaySelection>().is_err());
+    }
 }
 
 /* -------------------------------------------------------------------------- */
@@ -0,0 +1,607 @@
+use std::error::Error;
+use std::fmt::{Debug, Display};
+use std::hash::Hash;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+use std::str::FromStr;
+
+use crate::grid::Position;
+
+/// An integer type that can be used as coordinate of a [`Point2`] or [`Point3`].
+pub trait Coordinate:
+    Copy
+    + Debug
+    + Display
+    + Default
+    + Eq
+    + Ord
+    + Hash
+    + FromStr
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+
+    fn checked_add(self, rhs: Self) -> Option<Self>;
+    fn checked_sub(self, rhs: Self) -> Option<Self>;
+    fn to_usize(self) -> Option<usize>;
+    fn from_usize(value: usize) -> Option<Self>;
+
+    /// The absolute difference of two values.
+    fn distance(self, other: Self) -> Self {
+        if self > other {
+            self - other
+        } else {
+            other - self
+        }
+    }
+}
+
+/// A [`Coordinate`] that can be negative, which is required to move a point by a direction.
+pub trait SignedCoordinate: Coordinate + Neg<Output = Self> {}
+
+macro_rules! impl_coordinate {
+    ($($t:ty),*) => {$(
+        impl Coordinate for $t {
+            const ZERO: Self = 0;
+            const ONE: Self = 1;
+
+            fn checked_add(self, rhs: Self) -> Option<Self> {
+                <$t>::checked_add(self, rhs)
+            }
+
+            fn checked_sub(self, rhs: Self) -> Option<Self> {
+                <$t>::checked_sub(self, rhs)
+            }
+
+            fn to_usize(self) -> Option<usize> {
+                usize::try_from(self).ok()
+            }
+
+            fn from_usize(value: usize) -> Option<Self> {
+                Self::try_from(value).ok()
+            }
+        }
+    )*};
+}
+
+impl_coordinate!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+impl SignedCoordinate for i8 {}
+impl SignedCoordinate for i16 {}
+impl SignedCoordinate for i32 {}
+impl SignedCoordinate for i64 {}
+impl SignedCoordinate for i128 {}
+impl SignedCoordinate for isize {}
+
+/* -------------------------------------------------------------------------- */
+
+/// A point in two dimensions, with `y` growing downwards like the rows of the puzzle input.
+///
+/// ```
+/// # use advent_of_code::geom::{Direction4, Point2};
+/// let point = Point2::new(2_i32, 3) + Direction4::Up;
+/// assert_eq!(point, Point2::new(2, 2));
+/// assert_eq!(point.manhattan(Point2::new(0, 0)), 4);
+/// assert_eq!(Point2::new(-1_i32, 0).to_position(), None);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point2<T> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T: Coordinate> Point2<T> {
+    pub const fn new(x: T, y: T) -> Self {
+        Self { x, y }
+    }
+
+    pub fn manhattan(self, other: Self) -> T {
+        self.x.distance(other.x) + self.y.distance(other.y)
+    }
+
+    /// Converts the point to a [`Grid`](crate::grid::Grid) position, [`None`] if a coordinate is negative or too large.
+    pub fn to_position(self) -> Option<Position> {
+        Some((self.x.to_usize()?, self.y.to_usize()?))
+    }
+
+    pub fn from_position((x, y): Position) -> Option<Self> {
+        Some(Self::new(T::from_usize(x)?, T::from_usize(y)?))
+    }
+
+    /// Moves the point one step in a direction, [`None`] if a coordinate would overflow.
+    /// Unlike `+`, this also works for unsigned coordinates.
+    pub fn checked_step(self, direction: Direction4) -> Option<Self> {
+        let (x, y) = (self.x, self.y);
+        Some(match direction {
+            Direction4::Up => Self::new(x, y.checked_sub(T::ONE)?),
+            Direction4::Right => Self::new(x.checked_add(T::ONE)?, y),
+            Direction4::Down => Self::new(x, y.checked_add(T::ONE)?),
+            Direction4::Left => Self::new(x.checked_sub(T::ONE)?, y),
+        })
+    }
+}
+
+impl<T: SignedCoordinate> Point2<T> {
+    /// The four orthogonal neighbours in the order of [`Direction4::ALL`].
+    pub fn neighbours4(self) -> impl Iterator<Item = Self> {
+        Direction4::ALL
+            .into_iter()
+            .map(move |direction| self + direction)
+    }
+
+    /// All eight neighbours in the order of [`Direction8::ALL`].
+    pub fn neighbours8(self) -> impl Iterator<Item = Self> {
+        Direction8::ALL
+            .into_iter()
+            .map(move |direction| self + direction)
+    }
+}
+
+impl<T: Coordinate> Add for Point2<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl<T: Coordinate> Sub for Point2<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl<T: Coordinate> Mul<T> for Point2<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        Self::new(self.x * rhs, self.y * rhs)
+    }
+}
+
+impl<T: SignedCoordinate> Neg for Point2<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::new(-self.x, -self.y)
+    }
+}
+
+impl<T: Coordinate> AddAssign for Point2<T> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<T: Coordinate> SubAssign for Point2<T> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl<T: SignedCoordinate> Add<Direction4> for Point2<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Direction4) -> Self::Output {
+        self + rhs.offset()
+    }
+}
+
+impl<T: SignedCoordinate> Add<Direction8> for Point2<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Direction8) -> Self::Output {
+        self + rhs.offset()
+    }
+}
+
+impl<T: SignedCoordinate> AddAssign<Direction4> for Point2<T> {
+    fn add_assign(&mut self, rhs: Direction4) {
+        *self = *self + rhs;
+    }
+}
+
+impl<T: SignedCoordinate> AddAssign<Direction8> for Point2<T> {
+    fn add_assign(&mut self, rhs: Direction8) {
+        *self = *self + rhs;
+    }
+}
+
+impl<T: Display> Display for Point2<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+/// Parses a point from comma-separated coordinates, e.g. `3,-4`.
+impl<T: Coordinate> FromStr for Point2<T> {
+    type Err = PointFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match parse_coordinates(s)?[..] {
+            [x, y] => Ok(Self::new(x, y)),
+            _ => Err(PointFromStrError),
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// A point in three dimensions.
+///
+/// ```
+/// # use advent_of_code::geom::Point3;
+/// let point: Point3<i64> = "1, 2, -3".parse().unwrap();
+/// assert_eq!(point + Point3::new(1, 1, 1), Point3::new(2, 3, -2));
+/// assert_eq!(point.manhattan(Point3::default()), 6);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point3<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+impl<T: Coordinate> Point3<T> {
+    pub const fn new(x: T, y: T, z: T) -> Self {
+        Self { x, y, z }
+    }
+
+    pub fn manhattan(self, other: Self) -> T {
+        self.x.distance(other.x) + self.y.distance(other.y) + self.z.distance(other.z)
+    }
+}
+
+impl<T: Coordinate> Add for Point3<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::new(self.x + rhs.x, self.y + rhs.y, self.z + rhs.z)
+    }
+}
+
+impl<T: Coordinate> Sub for Point3<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::new(self.x - rhs.x, self.y - rhs.y, self.z - rhs.z)
+    }
+}
+
+impl<T: Coordinate> Mul<T> for Point3<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        Self::new(self.x * rhs, self.y * rhs, self.z * rhs)
+    }
+}
+
+impl<T: SignedCoordinate> Neg for Point3<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::new(-self.x, -self.y, -self.z)
+    }
+}
+
+impl<T: Coordinate> AddAssign for Point3<T> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<T: Coordinate> SubAssign for Point3<T> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl<T: Display> Display for Point3<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {}, {})", self.x, self.y, self.z)
+    }
+}
+
+/// Parses a point from comma-separated coordinates, e.g. `1,2,-3`.
+impl<T: Coordinate> FromStr for Point3<T> {
+    type Err = PointFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match parse_coordinates(s)?[..] {
+            [x, y, z] => Ok(Self::new(x, y, z)),
+            _ => Err(PointFromStrError),
+        }
+    }
+}
+
+fn parse_coordinates<T: Coordinate>(s: &str) -> Result<Vec<T>, PointFromStrError> {
+    s.split(',')
+        .map(|coordinate| coordinate.trim().parse().map_err(|_| PointFromStrError))
+        .collect()
+}
+
+/// An error which can be returned when parsing a [`Point2`] or [`Point3`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PointFromStrError;
+
+impl Error for PointFromStrError {}
+
+impl Display for PointFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("expecting comma-separated integer coordinates, e.g. `3,-4`")
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// One of the four orthogonal directions.
+///
+/// ```
+/// # use advent_of_code::geom::Direction4;
+/// let direction: Direction4 = "R".parse().unwrap();
+/// assert_eq!(direction.turn_right(), Direction4::Down);
+/// assert_eq!(Direction4::try_from('^').unwrap().opposite(), Direction4::Down);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Direction4 {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction4 {
+    /// All directions, clockwise starting at [`Direction4::Up`].
+    pub const ALL: [Self; 4] = [
+        Direction4::Up,
+        Direction4::Right,
+        Direction4::Down,
+        Direction4::Left,
+    ];
+
+    fn index(self) -> usize {
+        self as usize
+    }
+
+    #[must_use]
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self.index() + 1) % 4]
+    }
+
+    #[must_use]
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self.index() + 3) % 4]
+    }
+
+    #[must_use]
+    pub fn opposite(self) -> Self {
+        Self::ALL[(self.index() + 2) % 4]
+    }
+
+    /// Whether the direction moves along the y axis.
+    pub fn is_vertical(self) -> bool {
+        matches!(self, Direction4::Up | Direction4::Down)
+    }
+
+    /// The change of a point's coordinates when moving one step in this direction.
+    pub fn offset<T: SignedCoordinate>(self) -> Point2<T> {
+        match self {
+            Direction4::Up => Point2::new(T::ZERO, -T::ONE),
+            Direction4::Right => Point2::new(T::ONE, T::ZERO),
+            Direction4::Down => Point2::new(T::ZERO, T::ONE),
+            Direction4::Left => Point2::new(-T::ONE, T::ZERO),
+        }
+    }
+}
+
+/// Parses `U`, `R`, `D`, `L` and the arrows `^`, `>`, `v`, `<`.
+impl TryFrom<char> for Direction4 {
+    type Error = DirectionFromStrError;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            'U' | '^' => Ok(Direction4::Up),
+            'R' | '>' => Ok(Direction4::Right),
+            'D' | 'v' => Ok(Direction4::Down),
+            'L' | '<' => Ok(Direction4::Left),
+            _ => Err(DirectionFromStrError),
+        }
+    }
+}
+
+impl FromStr for Direction4 {
+    type Err = DirectionFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut chars = s.trim().chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => Self::try_from(c),
+            _ => Err(DirectionFromStrError),
+        }
+    }
+}
+
+impl From<Direction4> for Direction8 {
+    fn from(direction: Direction4) -> Self {
+        match direction {
+            Direction4::Up => Direction8::Up,
+            Direction4::Right => Direction8::Right,
+            Direction4::Down => Direction8::Down,
+            Direction4::Left => Direction8::Left,
+        }
+    }
+}
+
+/// One of the eight directions, including diagonals.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Direction8 {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Direction8 {
+    /// All directions, clockwise starting at [`Direction8::Up`].
+    pub const ALL: [Self; 8] = [
+        Direction8::Up,
+        Direction8::UpRight,
+        Direction8::Right,
+        Direction8::DownRight,
+        Direction8::Down,
+        Direction8::DownLeft,
+        Direction8::Left,
+        Direction8::UpLeft,
+    ];
+
+    fn index(self) -> usize {
+        self as usize
+    }
+
+    /// Turns by 45 degrees clockwise.
+    #[must_use]
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self.index() + 1) % 8]
+    }
+
+    /// Turns by 45 degrees counterclockwise.
+    #[must_use]
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self.index() + 7) % 8]
+    }
+
+    #[must_use]
+    pub fn opposite(self) -> Self {
+        Self::ALL[(self.index() + 4) % 8]
+    }
+
+    pub fn is_diagonal(self) -> bool {
+        self.index() % 2 == 1
+    }
+
+    /// The change of a point's coordinates when moving one step in this direction.
+    pub fn offset<T: SignedCoordinate>(self) -> Point2<T> {
+        let (x, y) = match self {
+            Direction8::Up => (T::ZERO, -T::ONE),
+            Direction8::UpRight => (T::ONE, -T::ONE),
+            Direction8::Right => (T::ONE, T::ZERO),
+            Direction8::DownRight => (T::ONE, T::ONE),
+            Direction8::Down => (T::ZERO, T::ONE),
+            Direction8::DownLeft => (-T::ONE, T::ONE),
+            Direction8::Left => (-T::ONE, T::ZERO),
+            Direction8::UpLeft => (-T::ONE, -T::ONE),
+        };
+        Point2::new(x, y)
+    }
+}
+
+/// An error which can be returned when parsing a [`Direction4`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DirectionFromStrError;
+
+impl Error for DirectionFromStrError {}
+
+impl Display for DirectionFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("expecting a direction, one of `U`, `R`, `D`, `L`, `^`, `>`, `v` or `<`")
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Direction4, Direction8, Point2, Point3};
+
+    #[test]
+    fn point_arithmetic() {
+        let a = Point2::new(3_i64, -2);
+        let b = Point2::new(-1, 4);
+        assert_eq!(a + b, Point2::new(2, 2));
+        assert_eq!(a - b, Point2::new(4, -6));
+        assert_eq!(a * 3, Point2::new(9, -6));
+        assert_eq!(-a, Point2::new(-3, 2));
+        assert_eq!(a.manhattan(b), 10);
+
+        let mut c = a;
+        c += b;
+        c -= Point2::new(1, 1);
+        assert_eq!(c, Point2::new(1, 1));
+
+        let p = Point3::new(1_u8, 5, 2);
+        assert_eq!(p.manhattan(Point3::new(4, 1, 2)), 7);
+        assert_eq!(p - Point3::new(1, 1, 1), Point3::new(0, 4, 1));
+    }
+
+    #[test]
+    fn moves_points() {
+        let mut point = Point2::new(0_i32, 0);
+        point += Direction4::Right;
+        point += Direction8::DownRight;
+        assert_eq!(point, Point2::new(2, 1));
+        assert_eq!(
+            point.neighbours4().collect::<Vec<_>>(),
+            [
+                Point2::new(2, 0),
+                Point2::new(3, 1),
+                Point2::new(2, 2),
+                Point2::new(1, 1)
+            ]
+        );
+        assert_eq!(point.neighbours8().count(), 8);
+
+        let origin = Point2::new(0_usize, 0);
+        assert_eq!(origin.checked_step(Direction4::Up), None);
+        assert_eq!(
+            origin.checked_step(Direction4::Right),
+            Some(Point2::new(1, 0))
+        );
+    }
+
+    #[test]
+    fn converts_to_grid_positions() {
+        assert_eq!(Point2::new(3_i32, 4).to_position(), Some((3, 4)));
+        assert_eq!(Point2::new(3_i32, -4).to_position(), None);
+        assert_eq!(
+            Point2::<u8>::from_position((255, 0)),
+            Some(Point2::new(255, 0))
+        );
+        assert_eq!(Point2::<u8>::from_position((256, 0)), None);
+    }
+
+    #[test]
+    fn turns_directions() {
+        assert_eq!(Direction4::Up.turn_left(), Direction4::Left);
+        assert_eq!(Direction4::Left.turn_right(), Direction4::Up);
+        assert_eq!(Direction4::Right.opposite(), Direction4::Left);
+        assert!(Direction4::Down.is_vertical());
+
+        assert_eq!(Direction8::Up.turn_left(), Direction8::UpLeft);
+        assert_eq!(Direction8::UpLeft.turn_right(), Direction8::Up);
+        assert_eq!(Direction8::DownRight.opposite(), Direction8::UpLeft);
+        assert!(Direction8::DownLeft.is_diagonal());
+        assert_eq!(Direction8::from(Direction4::Left), Direction8::Left);
+    }
+
+    #[test]
+    fn parses_directions_and_points() {
+        let directions: Vec<Direction4> = "^>v<".chars().map(|c| c.try_into().unwrap()).collect();
+        assert_eq!(directions, Direction4::ALL);
+        assert_eq!("L".parse(), Ok(Direction4::Left));
+        assert!("LR".parse::<Direction4>().is_err());
+        assert!(Direction4::try_from('x').is_err());
+
+        assert_eq!("3,-4".parse(), Ok(Point2::new(3_i32, -4)));
+        assert!("3,-4".parse::<Point2<u32>>().is_err());
+        assert!("1,2".parse::<Point3<i32>>().is_err());
+        assert_eq!(Point3::new(1, 2, 3).to_string(), "(1, 2, 3)");
+    }
+}
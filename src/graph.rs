@@ -0,0 +1,666 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap};
+use std::hash::Hash;
+
+use crate::grid::{Grid, Position};
+
+/// A weighted edge to the node `to`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Edge {
+    pub to: usize,
+    pub weight: usize,
+}
+
+/// A directed, weighted graph with the nodes `0..node_count()`, stored as adjacency lists.
+/// Undirected graphs store every edge in both directions.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Graph {
+    edges: Vec<Vec<Edge>>,
+}
+
+impl Graph {
+    /// Creates a graph with `node_count` nodes and no edges.
+    pub fn new(node_count: usize) -> Self {
+        Self {
+            edges: vec![vec![]; node_count],
+        }
+    }
+
+    pub fn node_count(&self) -> usize {
+        self.edges.len()
+    }
+
+    /// Adds a node without edges and returns its index.
+    pub fn add_node(&mut self) -> usize {
+        self.edges.push(vec![]);
+        self.edges.len() - 1
+    }
+
+    pub fn add_edge(&mut self, from: usize, to: usize, weight: usize) {
+        self.edges[from].push(Edge { to, weight });
+    }
+
+    /// Adds an edge in both directions.
+    pub fn add_undirected_edge(&mut self, a: usize, b: usize, weight: usize) {
+        self.add_edge(a, b, weight);
+        self.add_edge(b, a, weight);
+    }
+
+    pub fn edges(&self, node: usize) -> &[Edge] {
+        &self.edges[node]
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// A maze contracted to its junctions: dead ends, forks and the kept positions.
+/// Corridors between junctions become edges weighted with their length in steps.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JunctionGraph {
+    pub graph: Graph,
+    /// The grid position of every node of the graph.
+    pub positions: Vec<Position>,
+}
+
+impl JunctionGraph {
+    /// Returns the node of a junction at a grid position.
+    pub fn node(&self, position: Position) -> Option<usize> {
+        self.positions.iter().position(|p| *p == position)
+    }
+}
+
+/// Contracts a maze in a grid to a graph of its junctions.
+/// `can_move(from, to)` decides whether a step between two neighbouring positions is possible,
+/// which allows one-way tiles such as slopes. Cells that can be entered and connect to other than
+/// exactly two neighbours become junctions, and so does every position in `keep`, e.g. the start and the end.
+///
+/// ```
+/// # use advent_of_code::{graph::contract_grid, grid::Grid};
+/// let grid: Grid<char> = "#.###\n#...#\n#.#.#\n#...#\n###.#".parse().unwrap();
+/// let maze = contract_grid(&grid, |_, to| grid[to] != '#', &[(1, 0), (3, 4)]);
+/// // the start, the end and the two forks of the loop.
+/// assert_eq!(maze.positions, [(1, 0), (1, 1), (3, 3), (3, 4)]);
+/// assert_eq!(maze.graph.edges(0)[0].weight, 1);
+/// ```
+pub fn contract_grid<T>(
+    grid: &Grid<T>,
+    can_move: impl Fn(Position, Position) -> bool,
+    keep: &[Position],
+) -> JunctionGraph {
+    // walls may be left according to `can_move`, but are never entered.
+    let is_open = Grid::from_vec(
+        grid.width(),
+        grid.iter()
+            .map(|(position, _)| {
+                grid.neighbours4(position)
+                    .any(|previous| can_move(previous, position))
+            })
+            .collect(),
+    )
+    .expect("grid has the same size");
+
+    let is_connected =
+        |a: Position, b: Position| is_open[a] && is_open[b] && (can_move(a, b) || can_move(b, a));
+
+    let positions: Vec<Position> = grid
+        .iter()
+        .map(|(position, _)| position)
+        .filter(|position| {
+            let connections = grid
+                .neighbours4(*position)
+                .filter(|next| is_connected(*position, *next))
+                .count();
+            keep.contains(position) || (is_open[*position] && connections != 2)
+        })
+        .collect();
+
+    let nodes: HashMap<Position, usize> = positions
+        .iter()
+        .enumerate()
+        .map(|(node, position)| (*position, node))
+        .collect();
+
+    let mut graph = Graph::new(positions.len());
+
+    for (&start, &from) in &nodes {
+        for first in grid.neighbours4(start) {
+            if !can_move(start, first) {
+                continue;
+            }
+
+            let (mut previous, mut current, mut steps) = (start, first, 1);
+
+            // follow the corridor until the next junction, corridor cells have exactly one way forward.
+            loop {
+                if let Some(&to) = nodes.get(&current) {
+                    graph.add_edge(from, to, steps);
+                    break;
+                }
+
+                let Some(next) = grid
+                    .neighbours4(current)
+                    .find(|next| *next != previous && can_move(current, *next))
+                else {
+                    break;
+                };
+
+                (previous, current, steps) = (current, next, steps + 1);
+            }
+        }
+    }
+
+    // edges were added in hash order, sort them so the graph is the same on every run.
+    for node in 0..graph.node_count() {
+        graph.edges[node].sort_unstable_by_key(|edge| (edge.to, edge.weight));
+    }
+
+    JunctionGraph { graph, positions }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Returns the length of the longest path from `start` to `end` that visits every node at most once,
+/// [`None`] if `end` can not be reached.
+///
+/// Visited nodes are tracked in a bitmask. A branch is abandoned when even taking the heaviest
+/// edge out of every node it could still visit would not beat the longest path found so far.
+///
+/// # Panics
+/// Panics if the graph has more than 64 nodes.
+pub fn longest_path(graph: &Graph, start: usize, end: usize) -> Option<usize> {
+    let node_count = graph.node_count();
+    assert!(
+        node_count <= 64,
+        "longest path search supports at most 64 nodes, got {node_count}"
+    );
+
+    // the adjacency of every node with the bit of each neighbour, so visited checks are a single `&`.
+    let adjacency: Vec<Vec<(u64, usize, usize)>> = (0..node_count)
+        .map(|node| {
+            graph
+                .edges(node)
+                .iter()
+                .map(|edge| (1 << edge.to, edge.to, edge.weight))
+                .collect()
+        })
+        .collect();
+
+    let max_weights: Vec<usize> = (0..node_count)
+        .map(|node| {
+            graph
+                .edges(node)
+                .iter()
+                .map(|edge| edge.weight)
+                .max()
+                .unwrap_or(0)
+        })
+        .collect();
+
+    // if only one node leads to the end, every path has to take that edge once it reaches the node.
+    let into_end: Vec<usize> = (0..node_count)
+        .filter(|node| graph.edges(*node).iter().any(|edge| edge.to == end))
+        .collect();
+    let gate = match into_end[..] {
+        [gate] if gate != start => Some(gate),
+        _ => None,
+    };
+
+    let search = Search {
+        adjacency,
+        max_weights,
+        end,
+        gate,
+    };
+
+    let mut best = None;
+    search.visit(start, 1 << start, 0, &mut best);
+    best
+}
+
+struct Search {
+    adjacency: Vec<Vec<(u64, usize, usize)>>,
+    max_weights: Vec<usize>,
+    end: usize,
+    gate: Option<usize>,
+}
+
+impl Search {
+    /// The length of the path so far plus the heaviest edge out of every node that can still be left.
+    fn upper_bound(&self, node: usize, visited: u64, length: usize) -> usize {
+        let mut bound = length + self.max_weights[node];
+        let mut unvisited = !visited & !(1 << self.end) & mask(self.max_weights.len());
+        while unvisited != 0 {
+            bound += self.max_weights[unvisited.trailing_zeros() as usize];
+            unvisited &= unvisited - 1;
+        }
+        bound
+    }
+
+    fn visit(&self, node: usize, visited: u64, length: usize, best: &mut Option<usize>) {
+        if node == self.end {
+            *best = Some(best.map_or(length, |best| best.max(length)));
+            return;
+        }
+
+        if best.is_some_and(|best| self.upper_bound(node, visited, length) <= best) {
+            return;
+        }
+
+        for &(bit, next, weight) in &self.adjacency[node] {
+            if visited & bit != 0 || (self.gate == Some(node) && next != self.end) {
+                continue;
+            }
+            self.visit(next, visited | bit, length + weight, best);
+        }
+    }
+}
+
+/// A bitmask with the lowest `count` bits set.
+fn mask(count: usize) -> u64 {
+    if count >= 64 {
+        u64::MAX
+    } else {
+        (1 << count) - 1
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Assigns consecutive node indices to keys, e.g. the names of the nodes in the puzzle input.
+///
+/// ```
+/// # use advent_of_code::graph::Interner;
+/// let mut names = Interner::default();
+/// assert_eq!(names.intern("jqt"), 0);
+/// assert_eq!(names.intern("rhn"), 1);
+/// assert_eq!(names.intern("jqt"), 0);
+/// assert_eq!(names.key(1), &"rhn");
+/// ```
+#[derive(Debug, Clone)]
+pub struct Interner<K> {
+    ids: HashMap<K, usize>,
+    keys: Vec<K>,
+}
+
+impl<K> Default for Interner<K> {
+    fn default() -> Self {
+        Self {
+            ids: HashMap::new(),
+            keys: vec![],
+        }
+    }
+}
+
+impl<K: Clone + Eq + Hash> Interner<K> {
+    /// Returns the index of a key, assigning the next free index to new keys.
+    pub fn intern(&mut self, key: K) -> usize {
+        if let Some(id) = self.ids.get(&key) {
+            return *id;
+        }
+        self.keys.push(key.clone());
+        self.ids.insert(key, self.keys.len() - 1);
+        self.keys.len() - 1
+    }
+
+    pub fn get(&self, key: &K) -> Option<usize> {
+        self.ids.get(key).copied()
+    }
+
+    pub fn key(&self, id: usize) -> &K {
+        &self.keys[id]
+    }
+
+    pub fn len(&self) -> usize {
+        self.keys.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// The connected components of a graph.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Components {
+    /// The component of every node. Components are numbered in the order of their lowest node.
+    pub labels: Vec<usize>,
+    /// The number of nodes of every component.
+    pub sizes: Vec<usize>,
+}
+
+/// Labels the connected components of an undirected graph.
+pub fn connected_components(graph: &Graph) -> Components {
+    let mut labels = vec![usize::MAX; graph.node_count()];
+    let mut sizes = vec![];
+
+    for root in 0..graph.node_count() {
+        if labels[root] != usize::MAX {
+            continue;
+        }
+
+        let label = sizes.len();
+        let mut size = 0;
+        let mut stack = vec![root];
+        labels[root] = label;
+
+        while let Some(node) = stack.pop() {
+            size += 1;
+            for edge in graph.edges(node) {
+                if labels[edge.to] == usize::MAX {
+                    labels[edge.to] = label;
+                    stack.push(edge.to);
+                }
+            }
+        }
+
+        sizes.push(size);
+    }
+
+    Components { labels, sizes }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// A minimum cut of a graph: a split of its nodes into two parts that are connected by edges of the least total weight.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MinCut {
+    /// The total weight of the cut edges.
+    pub weight: usize,
+    /// Whether each node is part of the first part.
+    pub is_first: Vec<bool>,
+    /// The cut edges, as pairs of nodes with the node of the first part first.
+    pub edges: Vec<(usize, usize)>,
+}
+
+impl MinCut {
+    /// The number of nodes in the first and the second part.
+    pub fn sizes(&self) -> (usize, usize) {
+        let first = self.is_first.iter().filter(|is_first| **is_first).count();
+        (first, self.is_first.len() - first)
+    }
+}
+
+/// Finds a global minimum cut of an undirected graph with the Stoer–Wagner algorithm.
+/// The result only depends on the graph, so the runtime is the same on every run.
+/// Returns [`None`] if the graph has less than two nodes.
+///
+/// ```
+/// # use advent_of_code::graph::{min_cut, Graph};
+/// // two triangles, joined by a single edge.
+/// let mut graph = Graph::new(6);
+/// for (a, b) in [(0, 1), (1, 2), (2, 0), (3, 4), (4, 5), (5, 3), (2, 3)] {
+///     graph.add_undirected_edge(a, b, 1);
+/// }
+/// let cut = min_cut(&graph).unwrap();
+/// assert_eq!(cut.weight, 1);
+/// assert_eq!(cut.sizes(), (3, 3));
+/// ```
+pub fn min_cut(graph: &Graph) -> Option<MinCut> {
+    let node_count = graph.node_count();
+    if node_count < 2 {
+        return None;
+    }
+
+    // weights between the merged nodes, a merged node keeps the index of one of its nodes.
+    let mut weights: Vec<HashMap<usize, usize>> = vec![HashMap::new(); node_count];
+    for (node, merged) in weights.iter_mut().enumerate() {
+        for edge in graph.edges(node).iter().filter(|edge| edge.to != node) {
+            *merged.entry(edge.to).or_default() += edge.weight;
+        }
+    }
+
+    let mut members: Vec<Vec<usize>> = (0..node_count).map(|node| vec![node]).collect();
+    let mut active: Vec<usize> = (0..node_count).collect();
+    let mut best: Option<(usize, Vec<usize>)> = None;
+
+    while active.len() > 1 {
+        let (s, t, cut_weight) = minimum_cut_phase(&weights, &active);
+
+        if best.as_ref().is_none_or(|(weight, _)| cut_weight < *weight) {
+            best = Some((cut_weight, members[t].clone()));
+        }
+
+        // merge `t` into `s`.
+        let merged = std::mem::take(&mut members[t]);
+        members[s].extend(merged);
+
+        for (node, weight) in std::mem::take(&mut weights[t]) {
+            weights[node].remove(&t);
+            if node != s {
+                *weights[s].entry(node).or_default() += weight;
+                *weights[node].entry(s).or_default() += weight;
+            }
+        }
+
+        active.retain(|node| *node != t);
+    }
+
+    let (weight, first) = best?;
+    let mut is_first = vec![false; node_count];
+    for node in first {
+        is_first[node] = true;
+    }
+
+    let edges = (0..node_count)
+        .filter(|node| is_first[*node])
+        .flat_map(|node| {
+            graph
+                .edges(node)
+                .iter()
+                .filter(|edge| !is_first[edge.to])
+                .map(move |edge| (node, edge.to))
+        })
+        .collect();
+
+    Some(MinCut {
+        weight,
+        is_first,
+        edges,
+    })
+}
+
+/// Adds the active nodes one by one, always the one most tightly connected to the nodes added so far.
+/// Returns the last two nodes and the weight of the cut between the last node and all others.
+fn minimum_cut_phase(weights: &[HashMap<usize, usize>], active: &[usize]) -> (usize, usize, usize) {
+    let mut connection = vec![0; weights.len()];
+    let mut is_added = vec![false; weights.len()];
+    let mut queue: BinaryHeap<(usize, Reverse<usize>)> =
+        active.iter().map(|node| (0, Reverse(*node))).collect();
+
+    let (mut previous, mut last) = (active[0], active[0]);
+
+    while let Some((weight, Reverse(node))) = queue.pop() {
+        // skip outdated entries, the node was queued again with a stronger connection.
+        if is_added[node] || weight != connection[node] {
+            continue;
+        }
+
+        is_added[node] = true;
+        (previous, last) = (last, node);
+
+        for (next, weight) in &weights[node] {
+            if !is_added[*next] {
+                connection[*next] += weight;
+                queue.push((connection[*next], Reverse(*next)));
+            }
+        }
+    }
+
+    (previous, last, connection[last])
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{connected_components, contract_grid, longest_path, min_cut, Graph, Interner};
+    use crate::grid::Grid;
+
+    /// Example of day 23 of 2023.
+    const TRAILS: &str = "#.#####################
+#.......#########...###
+#######.#########.#.###
+###.....#.>.>.###.#.###
+###v#####.#v#.###.#.###
+###.>...#.#.#.....#...#
+###v###.#.#.#########.#
+###...#.#.#.......#...#
+#####.#.#.#######.#.###
+#.....#.#.#.......#...#
+#.#####.#.#.#########v#
+#.#...#...#...###...>.#
+#.#.#v#######v###.###v#
+#...#.>.#...>.>.#.###.#
+#####v#.#.###v#.#.###.#
+#.....#...#...#.#.#...#
+#.#########.###.#.#.###
+#...###...#...#...#.###
+###.###.#.###v#####v###
+#...#...#.#.>.>.#.>.###
+#.###.###.#.###.#.#v###
+#.....###...###...#...#
+#####################.#";
+
+    fn hike(slippery: bool) -> Option<usize> {
+        let grid: Grid<char> = TRAILS.parse().unwrap();
+        let start = (1, 0);
+        let end = (grid.width() - 2, grid.height() - 1);
+
+        let maze = contract_grid(
+            &grid,
+            |from, to| {
+                let downhill = match grid[from] {
+                    '>' => Some((from.0 + 1, from.1)),
+                    'v' => Some((from.0, from.1 + 1)),
+                    _ => None,
+                };
+                grid[to] != '#' && (!slippery || downhill.is_none_or(|downhill| downhill == to))
+            },
+            &[start, end],
+        );
+
+        longest_path(
+            &maze.graph,
+            maze.node(start).unwrap(),
+            maze.node(end).unwrap(),
+        )
+    }
+
+    #[test]
+    fn contracts_maze_to_junctions() {
+        let grid: Grid<char> = TRAILS.parse().unwrap();
+        let maze = contract_grid(&grid, |_, to| grid[to] != '#', &[(1, 0), (21, 22)]);
+
+        assert_eq!(maze.graph.node_count(), 9);
+        assert_eq!(maze.node((1, 0)), Some(0));
+        assert_eq!(maze.node((2, 1)), None);
+
+        // the start leads to a single junction, through a corridor of 15 steps.
+        let edges = maze.graph.edges(0);
+        assert_eq!(edges.len(), 1);
+        assert_eq!(maze.positions[edges[0].to], (3, 5));
+        assert_eq!(edges[0].weight, 15);
+    }
+
+    #[test]
+    fn finds_longest_hike() {
+        assert_eq!(hike(true), Some(94));
+        assert_eq!(hike(false), Some(154));
+    }
+
+    #[test]
+    fn longest_path_visits_nodes_once() {
+        // 0 - 1 - 3 is short, 0 - 2 - 1 - 3 is longer, the cycle 1 - 2 can not be repeated.
+        let mut graph = Graph::new(5);
+        graph.add_undirected_edge(0, 1, 1);
+        graph.add_undirected_edge(0, 2, 5);
+        graph.add_undirected_edge(1, 2, 5);
+        graph.add_undirected_edge(1, 3, 1);
+        assert_eq!(longest_path(&graph, 0, 3), Some(11));
+        assert_eq!(longest_path(&graph, 0, 4), None);
+        assert_eq!(longest_path(&graph, 3, 3), Some(0));
+
+        graph.add_edge(4, 0, 2);
+        assert_eq!(longest_path(&graph, 4, 3), Some(13));
+        assert_eq!(longest_path(&graph, 3, 4), None);
+    }
+
+    /// Example of day 25 of 2023.
+    const WIRING: &str = "jqt: rhn xhk nvd
+rsh: frs pzl lsr
+xhk: hfx
+cmg: qnr nvd lhk bvb
+rhn: xhk bvb hfx
+bvb: xhk hfx
+pzl: lsr hfx nvd
+qnr: nvd
+ntq: jqt hfx bvb xhk
+nvd: lhk
+lsr: lhk
+rzs: qnr cmg lsr rsh
+frs: qnr lhk lsr";
+
+    fn wiring() -> (Graph, Interner<&'static str>) {
+        let mut names = Interner::default();
+        let mut edges = vec![];
+        for line in WIRING.lines() {
+            let (from, to) = line.split_once(": ").unwrap();
+            let from = names.intern(from);
+            for to in to.split_whitespace() {
+                edges.push((from, names.intern(to)));
+            }
+        }
+
+        let mut graph = Graph::new(names.len());
+        for (a, b) in edges {
+            graph.add_undirected_edge(a, b, 1);
+        }
+        (graph, names)
+    }
+
+    #[test]
+    fn finds_minimum_cut() {
+        let (graph, names) = wiring();
+        let cut = min_cut(&graph).unwrap();
+        assert_eq!(cut.weight, 3);
+        assert_eq!(cut.sizes().0 * cut.sizes().1, 54);
+
+        let mut edges: Vec<[&str; 2]> = cut
+            .edges
+            .iter()
+            .map(|(a, b)| {
+                let mut edge = [*names.key(*a), *names.key(*b)];
+                edge.sort_unstable();
+                edge
+            })
+            .collect();
+        edges.sort_unstable();
+        assert_eq!(edges, [["bvb", "cmg"], ["hfx", "pzl"], ["jqt", "nvd"]]);
+
+        assert_eq!(min_cut(&Graph::new(1)), None);
+    }
+
+    #[test]
+    fn labels_connected_components() {
+        let (mut graph, _) = wiring();
+        let cut = min_cut(&graph).unwrap();
+        assert_eq!(connected_components(&graph).sizes, [15]);
+
+        // removing the cut edges splits the graph into the two parts of the cut.
+        let mut split = Graph::new(graph.node_count());
+        for node in 0..graph.node_count() {
+            for edge in graph.edges(node) {
+                if cut.is_first[node] == cut.is_first[edge.to] {
+                    split.add_edge(node, edge.to, edge.weight);
+                }
+            }
+        }
+        let components = connected_components(&split);
+        assert_eq!(components.sizes.len(), 2);
+        assert_eq!(components.sizes.iter().product::<usize>(), 54);
+        assert_eq!(components.labels[0], 0);
+
+        graph.add_node();
+        assert_eq!(connected_components(&graph).sizes, [15, 1]);
+    }
+}
@@ -0,0 +1,452 @@
+use std::error::Error;
+use std::fmt::Display;
+use std::ops::{Index, IndexMut};
+use std::str::FromStr;
+
+/// A position in a [`Grid`] as `(x, y)`, with `(0, 0)` in the top left corner.
+pub type Position = (usize, usize);
+
+/// Offsets of the four orthogonal neighbours: up, right, down, left.
+const OFFSETS_4: [(isize, isize); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+
+/// Offsets of the eight neighbours, clockwise starting at up.
+const OFFSETS_8: [(isize, isize); 8] = [
+    (0, -1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+    (0, 1),
+    (-1, 1),
+    (-1, 0),
+    (-1, -1),
+];
+
+/// A dense two-dimensional grid, stored row by row in a single [`Vec`].
+///
+/// ```
+/// # use advent_of_code::grid::Grid;
+/// let grid: Grid<char> = "#..\n.#.".parse().unwrap();
+/// assert_eq!((grid.width(), grid.height()), (3, 2));
+/// assert_eq!(grid[(1, 1)], '#');
+/// assert_eq!(grid.get(3, 0), None);
+/// assert_eq!(grid.to_string(), "#..\n.#.");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Creates a grid from its cells in row order. Returns [`None`] if the cells do not fill whole rows.
+    pub fn from_vec(width: usize, cells: Vec<T>) -> Option<Self> {
+        if width == 0 {
+            return cells.is_empty().then_some(Self {
+                width,
+                height: 0,
+                cells,
+            });
+        }
+        if !cells.len().is_multiple_of(width) {
+            return None;
+        }
+        Some(Self {
+            width,
+            height: cells.len() / width,
+            cells,
+        })
+    }
+
+    /// Parses a grid with one row per line, converting every character with `f`.
+    /// Returns an error if the lines differ in length or `f` rejects a character.
+    pub fn parse_with<E>(
+        s: &str,
+        mut f: impl FnMut(char) -> Result<T, E>,
+    ) -> Result<Self, GridFromStrError> {
+        let mut width = None;
+        let mut cells = vec![];
+
+        for (y, line) in s.lines().enumerate() {
+            let start = cells.len();
+            for (x, c) in line.chars().enumerate() {
+                cells.push(f(c).map_err(|_| GridFromStrError::InvalidCell { x, y, c })?);
+            }
+
+            let row_width = cells.len() - start;
+            if *width.get_or_insert(row_width) != row_width {
+                return Err(GridFromStrError::RaggedRow { y });
+            }
+        }
+
+        Ok(Self::from_vec(width.unwrap_or_default(), cells).expect("rows have equal width"))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, x: usize, y: usize) -> bool {
+        x < self.width && y < self.height
+    }
+
+    /// Returns the cell at a position, [`None`] if it is outside of the grid.
+    pub fn get(&self, x: usize, y: usize) -> Option<&T> {
+        self.contains(x, y).then(|| &self.cells[y * self.width + x])
+    }
+
+    pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut T> {
+        if self.contains(x, y) {
+            Some(&mut self.cells[y * self.width + x])
+        } else {
+            None
+        }
+    }
+
+    /// Returns the cell at a position of an infinite grid that repeats this grid in every direction.
+    ///
+    /// # Panics
+    /// Panics if the grid is empty.
+    pub fn get_wrapping(&self, x: isize, y: isize) -> &T {
+        let x = x.rem_euclid(self.width as isize) as usize;
+        let y = y.rem_euclid(self.height as isize) as usize;
+        &self.cells[y * self.width + x]
+    }
+
+    /// Moves a position by an offset, returns [`None`] if the result is outside of the grid.
+    pub fn offset(&self, (x, y): Position, (dx, dy): (isize, isize)) -> Option<Position> {
+        let x = x.checked_add_signed(dx)?;
+        let y = y.checked_add_signed(dy)?;
+        self.contains(x, y).then_some((x, y))
+    }
+
+    /// The orthogonal neighbours of a position that are inside of the grid: up, right, down, left.
+    pub fn neighbours4(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        OFFSETS_4
+            .iter()
+            .filter_map(move |offset| self.offset(position, *offset))
+    }
+
+    /// All neighbours of a position that are inside of the grid, including diagonals.
+    pub fn neighbours8(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        OFFSETS_8
+            .iter()
+            .filter_map(move |offset| self.offset(position, *offset))
+    }
+
+    /// An iterator over all cells and their positions in row order.
+    pub fn iter(&self) -> impl Iterator<Item = (Position, &T)> {
+        let width = self.width;
+        self.cells
+            .iter()
+            .enumerate()
+            .map(move |(i, cell)| ((i % width, i / width), cell))
+    }
+
+    /// # Panics
+    /// Panics if `y` is not a row of the grid.
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    /// An iterator over the rows from top to bottom.
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // `chunks` panics on a chunk size of zero, an empty grid has no rows either way.
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// # Panics
+    /// Panics if `x` is not a column of the grid.
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(
+            x < self.width,
+            "column {x} is out of bounds for a grid of width {}",
+            self.width
+        );
+        self.cells.iter().skip(x).step_by(self.width.max(1))
+    }
+
+    /// An iterator over the columns from left to right.
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// Returns the position of the first cell in row order that matches `predicate`.
+    pub fn position(&self, mut predicate: impl FnMut(&T) -> bool) -> Option<Position> {
+        self.iter()
+            .find(|(_, cell)| predicate(cell))
+            .map(|(position, _)| position)
+    }
+
+    /// Returns the first position of a value in row order.
+    pub fn find(&self, value: &T) -> Option<Position>
+    where
+        T: PartialEq,
+    {
+        self.position(|cell| cell == value)
+    }
+
+    /// Returns the positions of all cells that hold a value.
+    pub fn find_all<'a>(&'a self, value: &'a T) -> impl Iterator<Item = Position> + 'a
+    where
+        T: PartialEq,
+    {
+        self.iter()
+            .filter(move |(_, cell)| *cell == value)
+            .map(|(position, _)| position)
+    }
+
+    /// Creates a grid of the same size by converting every cell.
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    fn from_positions(width: usize, height: usize, f: impl Fn(usize, usize) -> T) -> Self {
+        Self {
+            width,
+            height,
+            cells: (0..height)
+                .flat_map(|y| (0..width).map(move |x| (x, y)))
+                .map(|(x, y)| f(x, y))
+                .collect(),
+        }
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    /// Creates a grid with every cell set to `value`.
+    pub fn new(width: usize, height: usize, value: T) -> Self {
+        Self {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    /// Mirrors the grid along its main diagonal, rows become columns.
+    #[must_use]
+    pub fn transpose(&self) -> Self {
+        Self::from_positions(self.height, self.width, |x, y| self[(y, x)].clone())
+    }
+
+    /// Rotates the grid by 90 degrees clockwise.
+    #[must_use]
+    pub fn rotate_cw(&self) -> Self {
+        Self::from_positions(self.height, self.width, |x, y| {
+            self[(y, self.height - 1 - x)].clone()
+        })
+    }
+
+    /// Rotates the grid by 90 degrees counterclockwise.
+    #[must_use]
+    pub fn rotate_ccw(&self) -> Self {
+        Self::from_positions(self.height, self.width, |x, y| {
+            self[(self.width - 1 - y, x)].clone()
+        })
+    }
+
+    /// Mirrors the grid from left to right.
+    #[must_use]
+    pub fn flip_horizontal(&self) -> Self {
+        Self::from_positions(self.width, self.height, |x, y| {
+            self[(self.width - 1 - x, y)].clone()
+        })
+    }
+
+    /// Mirrors the grid from top to bottom.
+    #[must_use]
+    pub fn flip_vertical(&self) -> Self {
+        Self::from_positions(self.width, self.height, |x, y| {
+            self[(x, self.height - 1 - y)].clone()
+        })
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): Position) -> &Self::Output {
+        self.get(x, y).unwrap_or_else(|| {
+            panic!(
+                "position ({x}, {y}) is outside of the {}x{} grid",
+                self.width, self.height
+            )
+        })
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, (x, y): Position) -> &mut Self::Output {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(x, y).unwrap_or_else(|| {
+            panic!("position ({x}, {y}) is outside of the {width}x{height} grid")
+        })
+    }
+}
+
+/// Displays one line per row, without a trailing line break.
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Parses a grid with one row per line. Cells can be any type that converts from [`char`],
+/// e.g. [`char`], [`u8`] or an enum of the tiles of a day.
+impl<T: TryFrom<char>> FromStr for Grid<T> {
+    type Err = GridFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse_with(s, T::try_from)
+    }
+}
+
+/// An error which can be returned when parsing a [`Grid`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GridFromStrError {
+    /// A row has a different length than the first row.
+    RaggedRow { y: usize },
+    /// A character could not be converted to a cell.
+    InvalidCell { x: usize, y: usize, c: char },
+}
+
+impl Error for GridFromStrError {}
+
+impl Display for GridFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GridFromStrError::RaggedRow { y } => {
+                write!(f, "row {y} differs in length from the first row")
+            }
+            GridFromStrError::InvalidCell { x, y, c } => {
+                write!(f, "invalid cell `{c}` at ({x}, {y})")
+            }
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Grid, GridFromStrError};
+
+    fn grid() -> Grid<char> {
+        "abc\ndef".parse().unwrap()
+    }
+
+    #[test]
+    fn parses_grid() {
+        let grid = grid();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(2, 1)], 'f');
+        assert_eq!(grid.row(1), ['d', 'e', 'f']);
+
+        let bytes: Grid<u8> = "ab\ncd\n".parse().unwrap();
+        assert_eq!(bytes[(1, 1)], b'd');
+
+        assert_eq!(
+            "ab\nc".parse::<Grid<char>>(),
+            Err(GridFromStrError::RaggedRow { y: 1 })
+        );
+        assert_eq!(
+            Grid::parse_with("0a", |c| c.to_digit(10).ok_or(())),
+            Err(GridFromStrError::InvalidCell { x: 1, y: 0, c: 'a' })
+        );
+        assert_eq!("".parse::<Grid<char>>().unwrap().height(), 0);
+    }
+
+    #[test]
+    fn accesses_cells() {
+        let mut grid = grid();
+        assert_eq!(grid.get(3, 0), None);
+        assert_eq!(grid.get_wrapping(-1, 2), &'c');
+        assert_eq!(grid.get_wrapping(4, -1), &'e');
+
+        grid[(0, 0)] = 'x';
+        *grid.get_mut(1, 1).unwrap() = 'y';
+        assert_eq!(grid.to_string(), "xbc\ndyf");
+    }
+
+    #[test]
+    fn iterates_neighbours() {
+        let grid = grid();
+        assert_eq!(
+            grid.neighbours4((0, 0)).collect::<Vec<_>>(),
+            [(1, 0), (0, 1)]
+        );
+        assert_eq!(
+            grid.neighbours4((1, 1)).collect::<Vec<_>>(),
+            [(1, 0), (2, 1), (0, 1)]
+        );
+        assert_eq!(
+            grid.neighbours8((1, 1)).collect::<Vec<_>>(),
+            [(1, 0), (2, 0), (2, 1), (0, 1), (0, 0)]
+        );
+    }
+
+    #[test]
+    fn iterates_rows_and_columns() {
+        let grid = grid();
+        assert_eq!(
+            grid.rows().collect::<Vec<_>>(),
+            [['a', 'b', 'c'], ['d', 'e', 'f']]
+        );
+        assert_eq!(
+            grid.columns()
+                .map(|column| column.collect::<String>())
+                .collect::<Vec<_>>(),
+            ["ad", "be", "cf"]
+        );
+    }
+
+    #[test]
+    #[should_panic = "column 3 is out of bounds"]
+    fn panics_on_column_out_of_bounds() {
+        grid().column(3);
+    }
+
+    #[test]
+    fn transforms_grid() {
+        let grid = grid();
+        assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf");
+        assert_eq!(grid.rotate_cw().to_string(), "da\neb\nfc");
+        assert_eq!(grid.rotate_ccw().to_string(), "cf\nbe\nad");
+        assert_eq!(grid.flip_horizontal().to_string(), "cba\nfed");
+        assert_eq!(grid.flip_vertical().to_string(), "def\nabc");
+        assert_eq!(grid.rotate_cw().rotate_ccw(), grid);
+    }
+
+    #[test]
+    fn finds_cells() {
+        let grid: Grid<char> = "#.#\n.#.".parse().unwrap();
+        assert_eq!(grid.find(&'.'), Some((1, 0)));
+        assert_eq!(grid.find(&'S'), None);
+        assert_eq!(
+            grid.find_all(&'#').collect::<Vec<_>>(),
+            [(0, 0), (2, 0), (1, 1)]
+        );
+        assert_eq!(
+            grid.map(|c| *c == '#').iter().filter(|(_, c)| **c).count(),
+            3
+        );
+    }
+}
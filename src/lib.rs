@@ -1,4 +1,10 @@
 mod day;
+pub mod geom;
+pub mod graph;
+pub mod grid;
+pub mod search;
 pub mod template;
+mod year;
 
 pub use day::*;
+pub use year::*;
@@ -1,31 +1,96 @@
-use advent_of_code::template::commands::{all, download, read, scaffold, solve};
+use advent_of_code::template::commands::{
+    all, bench_diff, download, progress, read, scaffold, solve, status, verify, watch,
+};
+use advent_of_code::template::{child, config::config, registry, runner::RunOptions, OutputFormat};
 use args::{parse, AppArguments};
 
 mod args {
-    use std::process;
+    use std::{path::PathBuf, process};
 
-    use advent_of_code::Day;
+    use advent_of_code::{
+        template::{config::config, report::ReportFormat, OutputFormat},
+        Day, DaySelection, PuzzleId, Year,
+    };
 
     pub enum AppArguments {
         Download {
-            day: Day,
+            puzzle: PuzzleId,
         },
         Read {
-            day: Day,
+            puzzle: PuzzleId,
         },
         Scaffold {
-            day: Day,
+            puzzle: PuzzleId,
         },
         Solve {
-            day: Day,
+            puzzle: PuzzleId,
             release: bool,
             time: bool,
+            mem: bool,
             submit: Option<u8>,
+            format: OutputFormat,
+            timeout: Option<u64>,
+            watch: bool,
         },
         All {
+            year: Year,
             release: bool,
             time: bool,
+            mem: bool,
+            format: OutputFormat,
+            timeout: Option<u64>,
+            jobs: usize,
+            days: Option<DaySelection>,
+            part: Option<u8>,
+            report: Option<ReportFormat>,
+            out: Option<PathBuf>,
+        },
+        Verify {
+            year: Year,
+        },
+        Progress {
+            year: Year,
+        },
+        Status {
+            year: Year,
         },
+        BenchDiff {
+            year: Year,
+            baseline: Option<String>,
+            threshold: f64,
+        },
+    }
+
+    /// Reads the `--year` argument, falling back to `AOC_YEAR` and the `year` of `aoc.toml`.
+    fn parse_year(args: &mut pico_args::Arguments) -> Result<Year, Box<dyn std::error::Error>> {
+        match args.opt_value_from_str("--year")? {
+            Some(year) => Ok(year),
+            None => config().default_year().ok_or_else(|| {
+                "no year specified. Pass `--year`, set AOC_YEAR or set `year` in aoc.toml.".into()
+            }),
+        }
+    }
+
+    fn parse_puzzle(
+        args: &mut pico_args::Arguments,
+    ) -> Result<PuzzleId, Box<dyn std::error::Error>> {
+        let year = parse_year(args)?;
+        let day: Day = args.free_from_str()?;
+        PuzzleId::new(year, day).ok_or_else(|| {
+            format!(
+                "day {day} is not part of {year}, expecting a day between 1 and {}.",
+                year.day_count()
+            )
+            .into()
+        })
+    }
+
+    fn parse_part(s: &str) -> Result<u8, String> {
+        match s {
+            "1" => Ok(1),
+            "2" => Ok(2),
+            _ => Err("expecting part 1 or 2.".into()),
+        }
     }
 
     pub fn parse() -> Result<AppArguments, Box<dyn std::error::Error>> {
@@ -33,23 +98,50 @@ mod args {
 
         let app_args = match args.subcommand()?.as_deref() {
             Some("all") => AppArguments::All {
+                year: parse_year(&mut args)?,
                 release: args.contains("--release"),
                 time: args.contains("--time"),
+                mem: args.contains("--mem"),
+                format: args.opt_value_from_str("--format")?.unwrap_or_default(),
+                timeout: args.opt_value_from_str("--timeout")?,
+                jobs: args.opt_value_from_str("--jobs")?.unwrap_or(1),
+                days: args.opt_value_from_str("--days")?,
+                part: args.opt_value_from_fn("--part", parse_part)?,
+                report: args.opt_value_from_str("--report")?,
+                out: args.opt_value_from_str("--out")?,
             },
             Some("download") => AppArguments::Download {
-                day: args.free_from_str()?,
+                puzzle: parse_puzzle(&mut args)?,
             },
             Some("read") => AppArguments::Read {
-                day: args.free_from_str()?,
+                puzzle: parse_puzzle(&mut args)?,
             },
             Some("scaffold") => AppArguments::Scaffold {
-                day: args.free_from_str()?,
+                puzzle: parse_puzzle(&mut args)?,
             },
             Some("solve") => AppArguments::Solve {
-                day: args.free_from_str()?,
+                puzzle: parse_puzzle(&mut args)?,
                 release: args.contains("--release"),
                 submit: args.opt_value_from_str("--submit")?,
                 time: args.contains("--time"),
+                mem: args.contains("--mem"),
+                format: args.opt_value_from_str("--format")?.unwrap_or_default(),
+                timeout: args.opt_value_from_str("--timeout")?,
+                watch: args.contains("--watch"),
+            },
+            Some("verify") => AppArguments::Verify {
+                year: parse_year(&mut args)?,
+            },
+            Some("progress") => AppArguments::Progress {
+                year: parse_year(&mut args)?,
+            },
+            Some("status") => AppArguments::Status {
+                year: parse_year(&mut args)?,
+            },
+            Some("bench-diff") => AppArguments::BenchDiff {
+                year: parse_year(&mut args)?,
+                baseline: args.opt_value_from_str("--baseline")?,
+                threshold: args.opt_value_from_str("--threshold")?.unwrap_or(10.0),
             },
             Some(x) => {
                 eprintln!("Unknown command: {x}");
@@ -70,23 +162,92 @@ mod args {
     }
 }
 
-fn main() {
+/// Runs the command in `aoc-runner` unless this binary is the runner, then exits with the status of the runner.
+/// Returns if the runner does not build, the command then runs the binary of each day, see [`registry`].
+fn run_in_runner(format: OutputFormat, mem: bool) {
+    if registry::is_registered() {
+        return;
+    }
+
+    if let Some(code) = child::run_runner(format, mem) {
+        std::process::exit(code);
+    }
+}
+
+pub(crate) fn main() {
     match parse() {
         Err(err) => {
             eprintln!("Error: {err}");
             std::process::exit(1);
         }
         Ok(args) => match args {
-            AppArguments::All { release, time } => all::handle(release, time),
-            AppArguments::Download { day } => download::handle(day),
-            AppArguments::Read { day } => read::handle(day),
-            AppArguments::Scaffold { day } => scaffold::handle(day),
+            AppArguments::All {
+                year,
+                release,
+                time,
+                mem,
+                format,
+                timeout,
+                jobs,
+                days,
+                part,
+                report,
+                out,
+            } => {
+                run_in_runner(format, mem);
+                let out = report.map(|format| out.unwrap_or_else(|| format.default_path(year)));
+                all::handle(
+                    year,
+                    release,
+                    RunOptions {
+                        is_timed: time,
+                        is_mem: mem,
+                        format,
+                        timeout: config().run.timeout(timeout),
+                        part,
+                        ..RunOptions::default()
+                    },
+                    jobs,
+                    days.as_ref(),
+                    report.zip(out.as_deref()),
+                );
+            }
+            AppArguments::Download { puzzle } => download::handle(puzzle),
+            AppArguments::Read { puzzle } => read::handle(puzzle),
+            AppArguments::Scaffold { puzzle } => scaffold::handle(puzzle),
             AppArguments::Solve {
-                day,
+                puzzle,
                 release,
                 time,
+                mem,
                 submit,
-            } => solve::handle(day, release, time, submit),
+                format,
+                timeout,
+                watch,
+            } => {
+                if watch {
+                    if time || mem || submit.is_some() || format != OutputFormat::Text {
+                        eprintln!(
+                            "Error: --watch can not be combined with --time, --mem, --submit or --format."
+                        );
+                        std::process::exit(1);
+                    }
+                    watch::handle(puzzle, release, timeout);
+                } else {
+                    solve::handle(puzzle, release, time, mem, submit, format, timeout);
+                }
+            }
+            AppArguments::Verify { year } => {
+                run_in_runner(OutputFormat::Text, false);
+                verify::handle(year);
+            }
+            AppArguments::Progress { year } => progress::handle(year),
+            AppArguments::Status { year } => status::handle(year),
+            AppArguments::BenchDiff {
+                year,
+                baseline,
+                threshold,
+            } => bench_diff::handle(year, baseline.as_deref(), threshold),
         },
     };
 }
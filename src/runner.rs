@@ -0,0 +1,11 @@
+//! The `advent_of_code` binary with every solution compiled in, see `advent_of_code::template::registry`.
+//! `all` and `verify` build and run it to call solutions in-process.
+#[path = "main.rs"]
+mod app;
+
+include!(concat!(env!("OUT_DIR"), "/solutions.rs"));
+
+fn main() {
+    advent_of_code::template::registry::register(SOLUTIONS);
+    app::main();
+}
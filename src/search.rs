@@ -0,0 +1,376 @@
+use std::cmp::Reverse;
+use std::collections::hash_map::Entry;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+/// A cost of moving between states, e.g. [`u32`] or [`usize`]. [`Default::default`] is the cost of not moving.
+pub trait Cost: Copy + Ord + Default + Add<Output = Self> {}
+
+impl<T: Copy + Ord + Default + Add<Output = T>> Cost for T {}
+
+/// Builds the path from the start to `state` by following the parents recorded during a search,
+/// e.g. those returned by [`dijkstra_all`] or [`bfs_distances`].
+/// The path starts with the start state and ends with `state`.
+pub fn reconstruct_path<S: Clone + Eq + Hash>(parents: &HashMap<S, S>, state: &S) -> Vec<S> {
+    let mut path = vec![state.clone()];
+    while let Some(parent) = parents.get(path.last().expect("path is not empty")) {
+        path.push(parent.clone());
+    }
+    path.reverse();
+    path
+}
+
+/// A state that was reached during a search, stored by index so states do not need to be [`Ord`].
+struct Node<S, C> {
+    state: S,
+    parent: Option<usize>,
+    cost: C,
+}
+
+fn node_path<S: Clone, C>(nodes: &[Node<S, C>], mut index: usize) -> Vec<S> {
+    let mut path = vec![nodes[index].state.clone()];
+    while let Some(parent) = nodes[index].parent {
+        path.push(nodes[parent].state.clone());
+        index = parent;
+    }
+    path.reverse();
+    path
+}
+
+/// Finds a cheapest path from `start` to a state that satisfies `is_goal` with A*.
+/// `successors` yields the states reachable from a state and the cost of each move,
+/// `heuristic` estimates the remaining cost and must never overestimate it.
+/// Returns the path, including `start` and the goal, and its cost.
+///
+/// ```
+/// # use advent_of_code::search::astar;
+/// // walk from 0 to 10 in steps of 1 or 3, a step of 3 costs 2.
+/// let (path, cost) = astar(
+///     0_i32,
+///     |n| [(n + 1, 1), (n + 3, 2)],
+///     |n| (10 - n).max(0) / 3,
+///     |n| *n == 10,
+/// )
+/// .unwrap();
+/// assert_eq!(cost, 7);
+/// assert_eq!(path.first(), Some(&0));
+/// assert_eq!(path.last(), Some(&10));
+/// ```
+pub fn astar<S, C, I>(
+    start: S,
+    mut successors: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> C,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Option<(Vec<S>, C)>
+where
+    S: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let mut queue = BinaryHeap::new();
+    queue.push(Reverse((heuristic(&start), C::default(), 0)));
+
+    let mut indices = HashMap::from([(start.clone(), 0)]);
+    let mut nodes = vec![Node {
+        state: start,
+        parent: None,
+        cost: C::default(),
+    }];
+
+    while let Some(Reverse((_, cost, index))) = queue.pop() {
+        // the state was reached more cheaply after this entry was queued.
+        if cost > nodes[index].cost {
+            continue;
+        }
+
+        let state = nodes[index].state.clone();
+        if is_goal(&state) {
+            return Some((node_path(&nodes, index), cost));
+        }
+
+        for (next, step) in successors(&state) {
+            let next_cost = cost + step;
+
+            let next_index = match indices.entry(next) {
+                Entry::Occupied(entry) => {
+                    let node = &mut nodes[*entry.get()];
+                    if next_cost >= node.cost {
+                        continue;
+                    }
+                    node.cost = next_cost;
+                    node.parent = Some(index);
+                    *entry.get()
+                }
+                Entry::Vacant(entry) => {
+                    nodes.push(Node {
+                        state: entry.key().clone(),
+                        parent: Some(index),
+                        cost: next_cost,
+                    });
+                    *entry.insert(nodes.len() - 1)
+                }
+            };
+
+            let estimate = next_cost + heuristic(&nodes[next_index].state);
+            queue.push(Reverse((estimate, next_cost, next_index)));
+        }
+    }
+
+    None
+}
+
+/// Finds a cheapest path from `start` to a state that satisfies `is_goal` with Dijkstra's algorithm.
+/// Returns the path, including `start` and the goal, and its cost.
+pub fn dijkstra<S, C, I>(
+    start: S,
+    successors: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Option<(Vec<S>, C)>
+where
+    S: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (S, C)>,
+{
+    astar(start, successors, |_| C::default(), is_goal)
+}
+
+/// Returns the cost of the cheapest path from `start` to every reachable state,
+/// and the parent of every state other than `start` on such a path, see [`reconstruct_path`].
+pub fn dijkstra_all<S, C, I>(
+    start: S,
+    mut successors: impl FnMut(&S) -> I,
+) -> (HashMap<S, C>, HashMap<S, S>)
+where
+    S: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let mut costs = HashMap::from([(start.clone(), C::default())]);
+    let mut parents = HashMap::new();
+    let mut queue = BinaryHeap::new();
+    let mut states = vec![start];
+    queue.push(Reverse((C::default(), 0)));
+
+    while let Some(Reverse((cost, index))) = queue.pop() {
+        let state = states[index].clone();
+        if costs.get(&state).is_some_and(|best| cost > *best) {
+            continue;
+        }
+
+        for (next, step) in successors(&state) {
+            let next_cost = cost + step;
+            if costs.get(&next).is_some_and(|best| next_cost >= *best) {
+                continue;
+            }
+            costs.insert(next.clone(), next_cost);
+            parents.insert(next.clone(), state.clone());
+            states.push(next);
+            queue.push(Reverse((next_cost, states.len() - 1)));
+        }
+    }
+
+    (costs, parents)
+}
+
+/// Finds a path with the fewest moves from `start` to a state that satisfies `is_goal` with a breadth-first search.
+/// Returns the path, including `start` and the goal.
+pub fn bfs<S, I>(
+    start: S,
+    mut successors: impl FnMut(&S) -> I,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Option<Vec<S>>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut parents: HashMap<S, S> = HashMap::new();
+    let mut visited = HashSet::from([start.clone()]);
+    let mut queue = VecDeque::from([start]);
+
+    while let Some(state) = queue.pop_front() {
+        if is_goal(&state) {
+            return Some(reconstruct_path(&parents, &state));
+        }
+
+        for next in successors(&state) {
+            if visited.insert(next.clone()) {
+                parents.insert(next.clone(), state.clone());
+                queue.push_back(next);
+            }
+        }
+    }
+
+    None
+}
+
+/// Returns the fewest number of moves from `start` to every reachable state,
+/// and the parent of every state other than `start` on such a path, see [`reconstruct_path`].
+///
+/// ```
+/// # use advent_of_code::search::{bfs_distances, reconstruct_path};
+/// // add one or double, without exceeding 10.
+/// let (distances, parents) =
+///     bfs_distances(1_u32, |n| [n + 1, n * 2].into_iter().filter(|n| *n <= 10));
+/// assert_eq!(distances[&1], 0);
+/// assert_eq!(distances[&10], 4);
+/// assert_eq!(reconstruct_path(&parents, &10).len(), 5);
+/// ```
+pub fn bfs_distances<S, I>(
+    start: S,
+    mut successors: impl FnMut(&S) -> I,
+) -> (HashMap<S, usize>, HashMap<S, S>)
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut distances = HashMap::from([(start.clone(), 0)]);
+    let mut parents = HashMap::new();
+    let mut queue = VecDeque::from([(start, 0)]);
+
+    while let Some((state, distance)) = queue.pop_front() {
+        for next in successors(&state) {
+            if let Entry::Vacant(entry) = distances.entry(next.clone()) {
+                entry.insert(distance + 1);
+                parents.insert(next.clone(), state.clone());
+                queue.push_back((next, distance + 1));
+            }
+        }
+    }
+
+    (distances, parents)
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::{astar, bfs, bfs_distances, dijkstra, dijkstra_all, reconstruct_path};
+    use crate::geom::{Direction4, Point2};
+    use crate::grid::Grid;
+
+    const MAZE: &str = "S.#.....
+.##.###.
+....#.#.
+.##...#E";
+
+    fn open_neighbours(grid: &Grid<char>, position: (usize, usize)) -> Vec<(usize, usize)> {
+        grid.neighbours4(position)
+            .filter(|next| grid[*next] != '#')
+            .collect()
+    }
+
+    #[test]
+    fn bfs_finds_shortest_path() {
+        let grid: Grid<char> = MAZE.parse().unwrap();
+        let start = grid.find(&'S').unwrap();
+        let end = grid.find(&'E').unwrap();
+
+        let path = bfs(start, |p| open_neighbours(&grid, *p), |p| *p == end).unwrap();
+        assert_eq!(path.len(), 15);
+        assert_eq!(path.first(), Some(&start));
+        assert_eq!(path.last(), Some(&end));
+
+        let (distances, parents) = bfs_distances(start, |p| open_neighbours(&grid, *p));
+        assert_eq!(distances[&end], 14);
+        assert_eq!(distances.get(&(2, 0)), None);
+        assert_eq!(reconstruct_path(&parents, &end), path);
+
+        assert_eq!(
+            bfs(start, |p| open_neighbours(&grid, *p), |p| *p == (2, 0)),
+            None
+        );
+    }
+
+    #[test]
+    fn dijkstra_prefers_cheaper_path() {
+        // 0 -> 1 -> 3 costs 3, 0 -> 2 -> 3 costs 5, 0 -> 3 costs 10.
+        let edges = HashMap::from([
+            (0, vec![(1, 1), (2, 1), (3, 10)]),
+            (1, vec![(3, 2)]),
+            (2, vec![(3, 4)]),
+        ]);
+        let successors = |n: &u8| edges.get(n).cloned().unwrap_or_default();
+
+        assert_eq!(
+            dijkstra(0, successors, |n| *n == 3),
+            Some((vec![0, 1, 3], 3))
+        );
+        assert_eq!(dijkstra(0, successors, |n| *n == 4), None::<(Vec<u8>, u32)>);
+
+        let (costs, parents): (HashMap<u8, u32>, _) = dijkstra_all(0, successors);
+        assert_eq!(costs, HashMap::from([(0, 0), (1, 1), (2, 1), (3, 3)]));
+        assert_eq!(reconstruct_path(&parents, &3), [0, 1, 3]);
+    }
+
+    #[test]
+    fn astar_matches_dijkstra() {
+        let grid: Grid<u32> =
+            Grid::parse_with("1163\n1381\n2136", |c| c.to_digit(10).ok_or(())).unwrap();
+        let end = (grid.width() - 1, grid.height() - 1);
+        let successors = |p: &(usize, usize)| {
+            grid.neighbours4(*p)
+                .map(|next| (next, grid[next]))
+                .collect::<Vec<_>>()
+        };
+
+        let (_, cost) = dijkstra((0, 0), successors, |p| *p == end).unwrap();
+        let (path, astar_cost) = astar(
+            (0, 0),
+            successors,
+            |p| (end.0 - p.0 + end.1 - p.1) as u32,
+            |p| *p == end,
+        )
+        .unwrap();
+        assert_eq!(cost, 13);
+        assert_eq!(astar_cost, cost);
+        assert_eq!(path.iter().skip(1).map(|p| grid[*p]).sum::<u32>(), cost);
+    }
+
+    /// Day 17 of 2023: a crucible moves at most three blocks in one direction and never turns back.
+    #[test]
+    fn searches_states_with_constraints() {
+        let grid: Grid<u32> = Grid::parse_with(
+            "2413432311323
+3215453535623
+3255245654254
+3446585845452
+4546657867536
+1438598798454
+4457876987766
+3637877979653
+4654967986887
+4564679986453
+1224686865563
+2546548887735
+4322674655533",
+            |c| c.to_digit(10).ok_or(()),
+        )
+        .unwrap();
+        let end = Point2::new(grid.width() as i32 - 1, grid.height() as i32 - 1);
+
+        let start = (Point2::new(0, 0), Direction4::Right, 0);
+        let successors = |(point, direction, straight): &(Point2<i32>, Direction4, u8)| {
+            [direction.turn_left(), *direction, direction.turn_right()]
+                .into_iter()
+                .filter(|next| *next != *direction || *straight < 3)
+                .filter_map(|next| {
+                    let position = (*point + next).to_position()?;
+                    let straight = if next == *direction { straight + 1 } else { 1 };
+                    let cost = *grid.get(position.0, position.1)?;
+                    Some(((*point + next, next, straight), cost))
+                })
+                .collect::<Vec<_>>()
+        };
+
+        let (_, cost) = dijkstra(start, successors, |(point, ..)| *point == end).unwrap();
+        assert_eq!(cost, 102);
+    }
+
+    #[test]
+    fn reconstructs_path_from_parents() {
+        let parents = HashMap::from([('c', 'b'), ('b', 'a')]);
+        assert_eq!(reconstruct_path(&parents, &'c'), ['a', 'b', 'c']);
+        assert_eq!(reconstruct_path(&parents, &'a'), ['a']);
+    }
+}
@@ -0,0 +1,206 @@
+/// Global allocator that counts allocations while a measurement is active on the current thread, used by `--mem`.
+/// Outside of a measurement, it forwards to the system allocator after a single thread-local load.
+/// It is only installed with the `mem` feature, without it [`measure`] counts nothing.
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    cell::Cell,
+};
+
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "mem")]
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+#[derive(Clone, Copy, Default)]
+struct Counters {
+    allocations: u64,
+    allocated_bytes: u64,
+    // signed, memory allocated before a measurement may be freed during it.
+    live_bytes: i64,
+    peak_bytes: i64,
+}
+
+thread_local! {
+    // counters are per thread, so that threads running at the same time do not skew each other's figures.
+    // [`None`] while no measurement is active on this thread.
+    static COUNTERS: Cell<Option<Counters>> = const { Cell::new(None) };
+}
+
+/// Whether the counting allocator is installed, i.e. the `mem` feature is enabled.
+pub const IS_INSTALLED: bool = cfg!(feature = "mem");
+
+pub struct CountingAllocator;
+
+impl CountingAllocator {
+    fn record_alloc(size: usize) {
+        Self::record(|counters| {
+            counters.allocations += 1;
+            counters.allocated_bytes += size as u64;
+            Self::record_live(counters, size as i64);
+        });
+    }
+
+    fn record_dealloc(size: usize) {
+        Self::record(|counters| Self::record_live(counters, -(size as i64)));
+    }
+
+    fn record_live(counters: &mut Counters, change: i64) {
+        counters.live_bytes += change;
+        counters.peak_bytes = counters.peak_bytes.max(counters.live_bytes);
+    }
+
+    fn record(update: impl FnOnce(&mut Counters)) {
+        // the thread-local may already be gone while a thread shuts down, its allocations are not measured then.
+        let _ = COUNTERS.try_with(|cell| {
+            if let Some(mut counters) = cell.get() {
+                update(&mut counters);
+                cell.set(Some(counters));
+            }
+        });
+    }
+}
+
+#[allow(clippy::cast_possible_wrap)]
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        Self::record_alloc(layout.size());
+        System.alloc(layout)
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        Self::record_alloc(layout.size());
+        System.alloc_zeroed(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        Self::record_dealloc(layout.size());
+        System.dealloc(ptr, layout);
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        // a reallocation counts as one allocation of the new size.
+        Self::record_dealloc(layout.size());
+        Self::record_alloc(new_size);
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+/// Allocation figures of a single run of a solution part.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MemStats {
+    /// Number of allocations, including reallocations.
+    pub allocations: u64,
+    /// Total number of bytes allocated.
+    pub bytes: u64,
+    /// Highest number of bytes allocated at the same time, relative to the start of the run.
+    pub peak_bytes: u64,
+}
+
+/// Runs `func` while counting allocations. Only allocations of the current thread are counted,
+/// not those of other threads, including threads spawned by `func`.
+pub fn measure<T>(func: impl FnOnce() -> T) -> (T, MemStats) {
+    COUNTERS.set(Some(Counters::default()));
+    let result = func();
+    let counters = COUNTERS.take().unwrap_or_default();
+
+    let stats = MemStats {
+        allocations: counters.allocations,
+        bytes: counters.allocated_bytes,
+        peak_bytes: u64::try_from(counters.peak_bytes).unwrap_or(0),
+    };
+
+    (result, stats)
+}
+
+/// Formats a number of bytes with a binary unit, e.g. `1.5 KiB`.
+#[must_use]
+pub fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+
+    if bytes < 1024 {
+        return format!("{bytes} B");
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    let mut value = bytes as f64 / 1024.0;
+    let mut unit = 0;
+
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+
+    format!("{value:.1} {}", UNITS[unit])
+}
+
+/// Reads a number of bytes formatted by [`format_bytes`]. Precision beyond the formatted digit is lost.
+#[must_use]
+pub fn parse_bytes(s: &str) -> Option<u64> {
+    const UNITS: [(&str, i32); 5] = [("B", 0), ("KiB", 1), ("MiB", 2), ("GiB", 3), ("TiB", 4)];
+
+    let (value, unit) = s.trim().split_once(' ')?;
+    let exponent = UNITS.iter().find(|(name, _)| *name == unit)?.1;
+    let value: f64 = value.parse().ok()?;
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    Some((value * 1024_f64.powi(exponent)).round() as u64)
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{format_bytes, measure, parse_bytes};
+    use std::{hint::black_box, thread};
+
+    #[test]
+    fn counts_allocations() {
+        let (len, stats) = measure(|| {
+            let a = black_box(vec![0_u8; 1000]);
+            let b = black_box(vec![0_u8; 2000]);
+            a.len() + b.len()
+        });
+        assert_eq!(len, 3000);
+        assert!(stats.allocations >= 2);
+        assert!(stats.bytes >= 3000);
+        assert!(stats.peak_bytes >= 3000);
+    }
+
+    #[test]
+    fn peak_is_lower_than_total_for_sequential_allocations() {
+        let (_, stats) = measure(|| {
+            for _ in 0..10 {
+                black_box(vec![0_u8; 10_000]);
+            }
+        });
+        assert!(stats.bytes >= 100_000);
+        assert!(stats.peak_bytes < 50_000);
+    }
+
+    #[test]
+    fn ignores_allocations_of_other_threads() {
+        let (_, stats) = measure(|| {
+            thread::spawn(|| black_box(vec![0_u8; 100_000]))
+                .join()
+                .unwrap()
+        });
+        assert!(stats.bytes < 100_000);
+    }
+
+    #[test]
+    fn formats_bytes() {
+        assert_eq!(format_bytes(512), "512 B");
+        assert_eq!(format_bytes(1536), "1.5 KiB");
+        assert_eq!(format_bytes(3 * 1024 * 1024), "3.0 MiB");
+    }
+
+    #[test]
+    fn parses_formatted_bytes() {
+        assert_eq!(parse_bytes("512 B"), Some(512));
+        assert_eq!(parse_bytes("1.5 KiB"), Some(1536));
+        assert_eq!(
+            parse_bytes(&format_bytes(3 * 1024 * 1024)),
+            Some(3 * 1024 * 1024)
+        );
+        assert_eq!(parse_bytes("1.5 kB"), None);
+    }
+}
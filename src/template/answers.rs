@@ -0,0 +1,177 @@
+/// Module that stores accepted answers in `data/{year}/answers/{day}.toml` (by default) and checks results against them.
+use std::{fmt::Display, fs, io, path::PathBuf};
+
+use serde::{Deserialize, Deserializer, Serialize};
+
+use crate::template::get_data_path;
+use crate::PuzzleId;
+
+#[derive(Debug)]
+pub enum Error {
+    Parser(String),
+    IO(io::Error),
+}
+
+impl From<std::io::Error> for Error {
+    fn from(e: std::io::Error) -> Self {
+        Error::IO(e)
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Parser(e) => write!(f, "could not parse answers file: {e}"),
+            Error::IO(e) => write!(f, "could not access answers file: {e}"),
+        }
+    }
+}
+
+/// The accepted answers of a day. Answers may be written as strings or integers.
+///
+/// ```toml
+/// part_1 = 42
+/// part_2 = "ABCDEF"
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Answers {
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "deserialize_answer"
+    )]
+    pub part_1: Option<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "deserialize_answer"
+    )]
+    pub part_2: Option<String>,
+}
+
+impl Answers {
+    /// Returns the stored answer for a part, if any.
+    #[must_use]
+    pub fn get(&self, part: u8) -> Option<&str> {
+        match part {
+            1 => self.part_1.as_deref(),
+            2 => self.part_2.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// Stores the answer for a part.
+    pub fn set(&mut self, part: u8, answer: &str) {
+        match part {
+            1 => self.part_1 = Some(answer.into()),
+            2 => self.part_2 = Some(answer.into()),
+            _ => {}
+        }
+    }
+
+    /// Checks a result against the stored answer of a part.
+    /// Returns [`None`] if no answer is stored for the part.
+    #[must_use]
+    pub fn check(&self, part: u8, result: Option<&str>) -> Option<bool> {
+        self.get(part)
+            .map(|expected| result.is_some_and(|result| result.trim() == expected.trim()))
+    }
+}
+
+fn deserialize_answer<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<String>, D::Error> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Answer {
+        Int(i64),
+        Str(String),
+    }
+
+    Ok(match Answer::deserialize(deserializer)? {
+        Answer::Int(x) => Some(x.to_string()),
+        Answer::Str(x) => Some(x),
+    })
+}
+
+#[must_use]
+pub fn get_answers_path(puzzle: PuzzleId) -> PathBuf {
+    get_data_path("answers", puzzle)
+}
+
+fn parse(s: &str) -> Result<Answers, Error> {
+    toml::from_str(s).map_err(|e| Error::Parser(e.to_string()))
+}
+
+fn serialize(answers: &Answers) -> Result<String, Error> {
+    toml::to_string(answers).map_err(|e| Error::Parser(e.to_string()))
+}
+
+/// Reads the stored answers for a day. Returns empty answers if there is no answers file yet.
+pub fn read(puzzle: PuzzleId) -> Result<Answers, Error> {
+    let path = get_answers_path(puzzle);
+    if !path.exists() {
+        return Ok(Answers::default());
+    }
+    parse(&fs::read_to_string(path)?)
+}
+
+/// Writes the answers for a day, replacing the existing answers file.
+pub fn write(puzzle: PuzzleId, answers: &Answers) -> Result<(), Error> {
+    let path = get_answers_path(puzzle);
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    fs::write(path, serialize(answers)?)?;
+    Ok(())
+}
+
+/// Stores the accepted answer for one part of a day, keeping the other part as is.
+pub fn record(puzzle: PuzzleId, part: u8, answer: &str) -> Result<(), Error> {
+    let mut answers = read(puzzle)?;
+    answers.set(part, answer);
+    write(puzzle, &answers)
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{parse, serialize, Answers};
+
+    #[test]
+    fn parses_integer_and_string_answers() {
+        let answers = parse("part_1 = 42\npart_2 = \"ABC\"\n").unwrap();
+        assert_eq!(answers.get(1), Some("42"));
+        assert_eq!(answers.get(2), Some("ABC"));
+    }
+
+    #[test]
+    fn parses_partial_answers() {
+        let answers = parse("part_1 = \"1337\"\n").unwrap();
+        assert_eq!(answers.get(1), Some("1337"));
+        assert_eq!(answers.get(2), None);
+        assert_eq!(parse("").unwrap(), Answers::default());
+    }
+
+    #[test]
+    fn errors_on_invalid_file() {
+        assert!(parse("part_1 = [1, 2]").is_err());
+    }
+
+    #[test]
+    fn round_trips() {
+        let mut answers = Answers::default();
+        answers.set(2, "1234");
+        let s = serialize(&answers).unwrap();
+        assert_eq!(s, "part_2 = \"1234\"\n");
+        assert_eq!(parse(&s).unwrap(), answers);
+    }
+
+    #[test]
+    fn checks_results() {
+        let answers = parse("part_1 = 42").unwrap();
+        assert_eq!(answers.check(1, Some("42")), Some(true));
+        assert_eq!(answers.check(1, Some("43")), Some(false));
+        assert_eq!(answers.check(1, None), Some(false));
+        assert_eq!(answers.check(2, Some("42")), None);
+    }
+}
@@ -0,0 +1,266 @@
+//! Minimal conversion of the HTML served by Advent of Code into Markdown and plain text.
+//! Only the handful of tags used in puzzle descriptions and answer responses are supported,
+//! anything else is rendered as its text content.
+
+/// Extracts the inner HTML of every `<article>` element.
+#[must_use]
+pub fn extract_articles(html: &str) -> Vec<&str> {
+    let mut articles = vec![];
+    let mut rest = html;
+
+    while let Some(start) = rest.find("<article") {
+        let Some(content_start) = rest[start..].find('>').map(|i| start + i + 1) else {
+            break;
+        };
+        let Some(end) = rest[content_start..]
+            .find("</article>")
+            .map(|i| content_start + i)
+        else {
+            break;
+        };
+        articles.push(&rest[content_start..end]);
+        rest = &rest[end + "</article>".len()..];
+    }
+
+    articles
+}
+
+/// Replaces the HTML entities used on Advent of Code with the characters they stand for.
+#[must_use]
+pub fn decode_entities(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    let mut rest = s;
+
+    while let Some(start) = rest.find('&') {
+        out.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        let decoded = rest.find(';').and_then(|end| {
+            let entity = &rest[1..end];
+            let c = match entity {
+                "lt" => Some('<'),
+                "gt" => Some('>'),
+                "amp" => Some('&'),
+                "quot" => Some('"'),
+                "apos" => Some('\''),
+                "nbsp" => Some(' '),
+                _ => entity
+                    .strip_prefix("#x")
+                    .and_then(|hex| u32::from_str_radix(hex, 16).ok())
+                    .or_else(|| entity.strip_prefix('#').and_then(|dec| dec.parse().ok()))
+                    .and_then(char::from_u32),
+            };
+            c.map(|c| (c, end))
+        });
+
+        match decoded {
+            Some((c, end)) => {
+                out.push(c);
+                rest = &rest[end + 1..];
+            }
+            None => {
+                out.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+
+    out.push_str(rest);
+    out
+}
+
+enum Token<'a> {
+    Open(&'a str, &'a str),
+    Close(&'a str),
+    Text(&'a str),
+}
+
+fn tokenize(html: &str) -> Vec<Token<'_>> {
+    let mut tokens = vec![];
+    let mut rest = html;
+
+    while !rest.is_empty() {
+        if let Some(tag) = rest.strip_prefix('<') {
+            let Some(end) = tag.find('>') else {
+                tokens.push(Token::Text(rest));
+                break;
+            };
+            let inner = tag[..end].trim_end_matches('/').trim();
+            if let Some(name) = inner.strip_prefix('/') {
+                tokens.push(Token::Close(name.trim()));
+            } else {
+                let (name, attrs) = inner.split_once(char::is_whitespace).unwrap_or((inner, ""));
+                tokens.push(Token::Open(name, attrs));
+            }
+            rest = &tag[end + 1..];
+        } else {
+            let end = rest.find('<').unwrap_or(rest.len());
+            tokens.push(Token::Text(&rest[..end]));
+            rest = &rest[end..];
+        }
+    }
+
+    tokens
+}
+
+fn get_attribute<'a>(attrs: &'a str, name: &str) -> Option<&'a str> {
+    let start = attrs.find(&format!("{name}=\""))? + name.len() + 2;
+    let end = attrs[start..].find('"')? + start;
+    Some(&attrs[start..end])
+}
+
+/// Converts an HTML fragment to Markdown. Relative links are prefixed with `base_url`.
+#[must_use]
+pub fn to_markdown(html: &str, base_url: &str) -> String {
+    let mut out = String::new();
+    let mut in_pre = false;
+    // buffered inline code, rendered on `</code>` so emphasis can wrap the whole code span.
+    let mut inline_code: Option<(String, bool)> = None;
+    let mut links: Vec<String> = vec![];
+
+    for token in tokenize(html) {
+        match token {
+            Token::Open(name, attrs) => match name {
+                "h2" => out.push_str("## "),
+                "pre" => {
+                    in_pre = true;
+                    out.push_str("```\n");
+                }
+                "code" if !in_pre => inline_code = Some((String::new(), false)),
+                "em" => match inline_code.as_mut() {
+                    Some((_, emphasized)) => *emphasized = true,
+                    None if !in_pre => out.push('*'),
+                    None => {}
+                },
+                "li" => out.push_str("- "),
+                "a" => {
+                    let href = get_attribute(attrs, "href").unwrap_or_default();
+                    links.push(if href.starts_with('/') {
+                        format!("{base_url}{href}")
+                    } else {
+                        href.to_string()
+                    });
+                    out.push('[');
+                }
+                _ => {}
+            },
+            Token::Close(name) => match name {
+                "h2" | "p" | "ul" => {
+                    while !out.ends_with("\n\n") {
+                        out.push('\n');
+                    }
+                }
+                "li" => out.push('\n'),
+                "pre" => {
+                    in_pre = false;
+                    if !out.ends_with('\n') {
+                        out.push('\n');
+                    }
+                    out.push_str("```\n\n");
+                }
+                "code" if !in_pre => {
+                    if let Some((code, emphasized)) = inline_code.take() {
+                        if emphasized {
+                            out.push_str(&format!("*`{code}`*"));
+                        } else {
+                            out.push_str(&format!("`{code}`"));
+                        }
+                    }
+                }
+                "em" if inline_code.is_none() && !in_pre => out.push('*'),
+                "a" => {
+                    let href = links.pop().unwrap_or_default();
+                    out.push_str(&format!("]({href})"));
+                }
+                _ => {}
+            },
+            // skip formatting whitespace between block elements.
+            Token::Text(text)
+                if !in_pre && text.trim().is_empty() && (out.is_empty() || out.ends_with('\n')) => {
+            }
+            Token::Text(text) => {
+                let text = decode_entities(text);
+                match inline_code.as_mut() {
+                    Some((code, _)) => code.push_str(&text),
+                    None => out.push_str(&text),
+                }
+            }
+        }
+    }
+
+    let mut markdown = out.trim().to_string();
+    markdown.push('\n');
+    markdown
+}
+
+/// Converts an HTML fragment to plain text by dropping all tags.
+#[must_use]
+pub fn to_text(html: &str) -> String {
+    let text: String = tokenize(html)
+        .into_iter()
+        .filter_map(|token| match token {
+            Token::Text(text) => Some(decode_entities(text)),
+            _ => None,
+        })
+        .collect();
+    text.trim().to_string()
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{decode_entities, extract_articles, to_markdown, to_text};
+
+    #[test]
+    fn decodes_entities() {
+        assert_eq!(
+            decode_entities("a &lt;b&gt; &amp; &#39;c&#x27; &foo"),
+            "a <b> & 'c' &foo"
+        );
+    }
+
+    #[test]
+    fn extracts_articles() {
+        let html = r#"<main><article class="day-desc"><h2>One</h2></article><p>x</p><article class="day-desc"><h2>Two</h2></article></main>"#;
+        assert_eq!(extract_articles(html), vec!["<h2>One</h2>", "<h2>Two</h2>"]);
+    }
+
+    #[test]
+    fn converts_puzzle_description() {
+        let html = concat!(
+            "<h2>--- Day 1: Test ---</h2>",
+            "<p>Read the <a href=\"/2023/about\">rules</a> &amp; go.</p>",
+            "<p>For example:</p>\n",
+            "<pre><code>1abc2\n<em>pqr</em>3stu8vwx\n</code></pre>\n",
+            "<ul><li>one <code>1</code></li><li>two</li></ul>",
+            "<p>The answer is <code><em>142</em></code>, <em>obviously</em>.</p>"
+        );
+        let expected = [
+            "## --- Day 1: Test ---",
+            "",
+            "Read the [rules](https://adventofcode.com/2023/about) & go.",
+            "",
+            "For example:",
+            "",
+            "```",
+            "1abc2",
+            "pqr3stu8vwx",
+            "```",
+            "",
+            "- one `1`",
+            "- two",
+            "",
+            "The answer is *`142`*, *obviously*.",
+            "",
+        ]
+        .join("\n");
+        assert_eq!(to_markdown(html, "https://adventofcode.com"), expected);
+    }
+
+    #[test]
+    fn converts_to_text() {
+        assert_eq!(
+            to_text("<p>That's the <em>right answer</em>! <a href=\"/\">[Return]</a></p>"),
+            "That's the right answer! [Return]"
+        );
+    }
+}
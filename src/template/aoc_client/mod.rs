@@ -0,0 +1,485 @@
+/// Built-in client for the Advent of Code website.
+/// Downloads inputs and puzzle descriptions and submits answers on behalf of the user's session.
+use std::{
+    env,
+    fmt::Display,
+    fs, io,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use regex::Regex;
+
+use crate::template::{config::config, get_data_path};
+use crate::PuzzleId;
+
+pub mod markdown;
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+static USER_AGENT: &str = concat!(
+    "advent_of_code/",
+    env!("CARGO_PKG_VERSION"),
+    " (+https://github.com/fspoettel/advent-of-code-rust)"
+);
+
+#[derive(Debug)]
+pub enum AocClientError {
+    SessionNotFound,
+    /// The request could not be sent or the response could not be read.
+    Transport(String),
+    /// The server answered with a non-success status, carrying the (shortened) response text.
+    BadStatus(u16, String),
+    /// The response did not look like anything the client knows how to handle.
+    UnexpectedResponse(String),
+    IoError(io::Error),
+}
+
+impl Display for AocClientError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AocClientError::SessionNotFound => write!(
+                f,
+                "no session cookie found. Set AOC_SESSION or create a \".adventofcode.session\" file in your home directory."
+            ),
+            AocClientError::Transport(e) => write!(f, "request failed: {e}"),
+            AocClientError::BadStatus(status, text) => {
+                write!(f, "server responded with status {status}: {text}")
+            }
+            AocClientError::UnexpectedResponse(text) => {
+                write!(f, "unexpected response from server: {text}")
+            }
+            AocClientError::IoError(e) => write!(f, "could not write output files to file system: {e}"),
+        }
+    }
+}
+
+impl From<io::Error> for AocClientError {
+    fn from(e: io::Error) -> Self {
+        AocClientError::IoError(e)
+    }
+}
+
+/// The verdict of the server for a submitted answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SubmissionOutcome {
+    Correct,
+    /// The answer is wrong and too high. `wait` is the cooldown before the next submission.
+    TooHigh {
+        wait: Option<Duration>,
+    },
+    /// The answer is wrong and too low. `wait` is the cooldown before the next submission.
+    TooLow {
+        wait: Option<Duration>,
+    },
+    /// The answer is wrong, without a hint. `wait` is the cooldown before the next submission.
+    Incorrect {
+        wait: Option<Duration>,
+    },
+    /// An answer was submitted too recently, nothing was checked.
+    RateLimited {
+        wait: Duration,
+    },
+    /// The part is already solved (or not unlocked yet), nothing was checked.
+    AlreadySolved,
+}
+
+impl Display for SubmissionOutcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let format_wait = |wait: &Option<Duration>| {
+            wait.map(|w| format!(" Wait {}s before trying again.", w.as_secs()))
+                .unwrap_or_default()
+        };
+        match self {
+            SubmissionOutcome::Correct => write!(f, "That's the right answer!"),
+            SubmissionOutcome::TooHigh { wait } => {
+                write!(
+                    f,
+                    "That's not the right answer, it is too high.{}",
+                    format_wait(wait)
+                )
+            }
+            SubmissionOutcome::TooLow { wait } => {
+                write!(
+                    f,
+                    "That's not the right answer, it is too low.{}",
+                    format_wait(wait)
+                )
+            }
+            SubmissionOutcome::Incorrect { wait } => {
+                write!(f, "That's not the right answer.{}", format_wait(wait))
+            }
+            SubmissionOutcome::RateLimited { wait } => write!(
+                f,
+                "You gave an answer too recently. Wait {}s before trying again.",
+                wait.as_secs()
+            ),
+            SubmissionOutcome::AlreadySolved => {
+                write!(f, "This part is already solved or not unlocked yet.")
+            }
+        }
+    }
+}
+
+/// Parses the text of the server's response to a submission.
+pub fn parse_submission_response(text: &str) -> Result<SubmissionOutcome, AocClientError> {
+    let wait = parse_wait(text);
+
+    if text.contains("That's the right answer") {
+        Ok(SubmissionOutcome::Correct)
+    } else if text.contains("That's not the right answer") {
+        if text.contains("too high") {
+            Ok(SubmissionOutcome::TooHigh { wait })
+        } else if text.contains("too low") {
+            Ok(SubmissionOutcome::TooLow { wait })
+        } else {
+            Ok(SubmissionOutcome::Incorrect { wait })
+        }
+    } else if text.contains("You gave an answer too recently") {
+        Ok(SubmissionOutcome::RateLimited {
+            wait: wait.unwrap_or(Duration::from_secs(60)),
+        })
+    } else if text.contains("You don't seem to be solving the right level") {
+        Ok(SubmissionOutcome::AlreadySolved)
+    } else {
+        Err(AocClientError::UnexpectedResponse(shorten(text)))
+    }
+}
+
+/// Parses the cooldown announced in a response, e.g. "You have 1m 5s left to wait" or "please wait 5 minutes".
+fn parse_wait(text: &str) -> Option<Duration> {
+    let left_to_wait = Regex::new(r"You have (?:(\d+)m )?(\d+)s left to wait").unwrap();
+    if let Some(captures) = left_to_wait.captures(text) {
+        let minutes: u64 = captures.get(1).map_or(Ok(0), |m| m.as_str().parse()).ok()?;
+        let seconds: u64 = captures[2].parse().ok()?;
+        return Some(Duration::from_secs(minutes * 60 + seconds));
+    }
+
+    let wait_minutes = Regex::new(r"(?i)wait (one|\d+) minutes?").unwrap();
+    let captures = wait_minutes.captures(text)?;
+    let minutes: u64 = match &captures[1] {
+        "one" => 1,
+        x => x.parse().ok()?,
+    };
+    Some(Duration::from_secs(minutes * 60))
+}
+
+fn shorten(text: &str) -> String {
+    let text = text.trim();
+    match text.char_indices().nth(200) {
+        Some((i, _)) => format!("{}…", &text[..i]),
+        None => text.to_string(),
+    }
+}
+
+pub struct AocClient {
+    base_url: String,
+    session: String,
+    agent: ureq::Agent,
+}
+
+impl AocClient {
+    #[must_use]
+    pub fn new(base_url: &str, session: &str) -> Self {
+        Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.trim().to_string(),
+            agent: ureq::AgentBuilder::new()
+                .user_agent(USER_AGENT)
+                .timeout(Duration::from_secs(30))
+                .build(),
+        }
+    }
+
+    /// Creates a client from the environment:
+    ///  1. the session cookie is read from `AOC_SESSION` or the session file configured in `aoc.toml`.
+    ///  2. the base url is read from `AOC_BASE_URL` and defaults to the Advent of Code website.
+    pub fn from_env() -> Result<Self, AocClientError> {
+        let session = get_session().ok_or(AocClientError::SessionNotFound)?;
+        let base_url = env::var("AOC_BASE_URL").unwrap_or_else(|_| DEFAULT_BASE_URL.into());
+        Ok(Self::new(&base_url, &session))
+    }
+
+    fn day_url(&self, puzzle: PuzzleId) -> String {
+        format!(
+            "{}/{}/day/{}",
+            self.base_url,
+            puzzle.year,
+            puzzle.day.into_inner()
+        )
+    }
+
+    fn handle_response(
+        response: Result<ureq::Response, ureq::Error>,
+    ) -> Result<String, AocClientError> {
+        match response {
+            Ok(response) => response
+                .into_string()
+                .map_err(|e| AocClientError::Transport(e.to_string())),
+            Err(ureq::Error::Status(status, response)) => {
+                let text = response.into_string().unwrap_or_default();
+                Err(AocClientError::BadStatus(status, shorten(&text)))
+            }
+            Err(e) => Err(AocClientError::Transport(e.to_string())),
+        }
+    }
+
+    fn get(&self, url: &str) -> Result<String, AocClientError> {
+        Self::handle_response(
+            self.agent
+                .get(url)
+                .set("Cookie", &format!("session={}", self.session))
+                .call(),
+        )
+    }
+
+    /// Downloads the puzzle input for a day.
+    pub fn get_input(&self, puzzle: PuzzleId) -> Result<String, AocClientError> {
+        self.get(&format!("{}/input", self.day_url(puzzle)))
+    }
+
+    /// Downloads the puzzle description for a day, converted to Markdown.
+    pub fn get_puzzle(&self, puzzle: PuzzleId) -> Result<String, AocClientError> {
+        let html = self.get(&self.day_url(puzzle))?;
+        let articles = markdown::extract_articles(&html);
+
+        if articles.is_empty() {
+            return Err(AocClientError::UnexpectedResponse(
+                "puzzle page contains no description.".into(),
+            ));
+        }
+
+        Ok(articles
+            .iter()
+            .map(|article| markdown::to_markdown(article, &self.base_url))
+            .collect::<Vec<_>>()
+            .join("\n"))
+    }
+
+    /// Submits an answer for one part of a day.
+    pub fn submit(
+        &self,
+        puzzle: PuzzleId,
+        part: u8,
+        answer: &str,
+    ) -> Result<SubmissionOutcome, AocClientError> {
+        let html = Self::handle_response(
+            self.agent
+                .post(&format!("{}/answer", self.day_url(puzzle)))
+                .set("Cookie", &format!("session={}", self.session))
+                .send_form(&[("level", &part.to_string()), ("answer", answer)]),
+        )?;
+
+        let text = markdown::extract_articles(&html).first().map_or_else(
+            || markdown::to_text(&html),
+            |article| markdown::to_text(article),
+        );
+
+        parse_submission_response(&text)
+    }
+}
+
+fn get_session() -> Option<String> {
+    if let Ok(session) = env::var("AOC_SESSION") {
+        if !session.trim().is_empty() {
+            return Some(session);
+        }
+    }
+
+    let session = fs::read_to_string(config().session.path()?).ok()?;
+    (!session.trim().is_empty()).then_some(session)
+}
+
+#[must_use]
+pub fn get_input_path(puzzle: PuzzleId) -> PathBuf {
+    get_data_path("inputs", puzzle)
+}
+
+#[must_use]
+pub fn get_puzzle_path(puzzle: PuzzleId) -> PathBuf {
+    get_data_path("puzzles", puzzle)
+}
+
+fn write_file(path: &Path, contents: &str) -> Result<(), AocClientError> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    fs::write(path, contents)?;
+    Ok(())
+}
+
+/// Downloads input and puzzle description of a day to the data directory.
+pub fn download(puzzle: PuzzleId) -> Result<(), AocClientError> {
+    let client = AocClient::from_env()?;
+
+    let input_path = get_input_path(puzzle);
+    let puzzle_path = get_puzzle_path(puzzle);
+
+    write_file(&input_path, &client.get_input(puzzle)?)?;
+    write_file(&puzzle_path, &client.get_puzzle(puzzle)?)?;
+
+    println!("---");
+    println!(
+        "🎄 Successfully wrote input to \"{}\".",
+        input_path.display()
+    );
+    println!(
+        "🎄 Successfully wrote puzzle to \"{}\".",
+        puzzle_path.display()
+    );
+    Ok(())
+}
+
+/// Downloads the puzzle description of a day, saves it and prints it to the terminal.
+pub fn read(puzzle: PuzzleId) -> Result<(), AocClientError> {
+    let client = AocClient::from_env()?;
+    let description = client.get_puzzle(puzzle)?;
+    write_file(&get_puzzle_path(puzzle), &description)?;
+    println!("{description}");
+    Ok(())
+}
+
+/// Submits an answer for one part of a day.
+pub fn submit(
+    puzzle: PuzzleId,
+    part: u8,
+    answer: &str,
+) -> Result<SubmissionOutcome, AocClientError> {
+    AocClient::from_env()?.submit(puzzle, part, answer)
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{parse_submission_response, AocClient, AocClientError, SubmissionOutcome};
+    use crate::puzzle;
+    use std::{
+        io::{BufRead, BufReader, Read, Write},
+        net::TcpListener,
+        sync::mpsc,
+        thread,
+        time::Duration,
+    };
+
+    /// Serves a single canned response on a local port, sends the received request back through the channel.
+    fn mock_server(status: &'static str, body: &'static str) -> (String, mpsc::Receiver<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let (tx, rx) = mpsc::channel();
+
+        thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+            let mut request = String::new();
+            let mut content_length = 0;
+
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if let Some(len) = line.to_lowercase().strip_prefix("content-length:") {
+                    content_length = len.trim().parse().unwrap();
+                }
+                request.push_str(&line);
+                if line == "\r\n" {
+                    break;
+                }
+            }
+
+            let mut content = vec![0; content_length];
+            reader.read_exact(&mut content).unwrap();
+            request.push_str(&String::from_utf8_lossy(&content));
+
+            let mut stream = stream;
+            write!(
+                stream,
+                "HTTP/1.1 {status}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                body.len()
+            )
+            .unwrap();
+            tx.send(request).unwrap();
+        });
+
+        (base_url, rx)
+    }
+
+    #[test]
+    fn downloads_input() {
+        let (base_url, rx) = mock_server("200 OK", "1 2 3\n");
+        let client = AocClient::new(&base_url, "secret");
+        assert_eq!(client.get_input(puzzle!(2023, 5)).unwrap(), "1 2 3\n");
+
+        let request = rx.recv().unwrap();
+        assert!(request.starts_with("GET /2023/day/5/input HTTP/1.1"));
+        assert!(request.contains("session=secret"));
+    }
+
+    #[test]
+    fn downloads_puzzle_as_markdown() {
+        let (base_url, _rx) = mock_server(
+            "200 OK",
+            "<main><article class=\"day-desc\"><h2>--- Day 5 ---</h2><p>Hi <em>there</em>.</p></article></main>",
+        );
+        let client = AocClient::new(&base_url, "secret");
+        assert_eq!(
+            client.get_puzzle(puzzle!(2023, 5)).unwrap(),
+            "## --- Day 5 ---\n\nHi *there*.\n"
+        );
+    }
+
+    #[test]
+    fn reports_bad_status_with_reason() {
+        let (base_url, _rx) = mock_server(
+            "400 Bad Request",
+            "Puzzle inputs differ by user.  Please log in to get your puzzle input.",
+        );
+        let client = AocClient::new(&base_url, "expired");
+        match client.get_input(puzzle!(2023, 5)) {
+            Err(AocClientError::BadStatus(400, text)) => assert!(text.contains("Please log in")),
+            x => panic!("unexpected result: {x:?}"),
+        }
+    }
+
+    #[test]
+    fn submits_answer() {
+        let (base_url, rx) = mock_server(
+            "200 OK",
+            "<main><article><p>That's the right answer!  You are one gold star closer.</p></article></main>",
+        );
+        let client = AocClient::new(&base_url, "secret");
+        assert_eq!(
+            client.submit(puzzle!(2023, 5), 2, "42").unwrap(),
+            SubmissionOutcome::Correct
+        );
+
+        let request = rx.recv().unwrap();
+        assert!(request.starts_with("POST /2023/day/5/answer HTTP/1.1"));
+        assert!(request.ends_with("level=2&answer=42"));
+    }
+
+    #[test]
+    fn parses_submission_outcomes() {
+        assert_eq!(
+            parse_submission_response("That's not the right answer; your answer is too high. If you're stuck, ... Please wait one minute before trying again. [Return to Day 1]").unwrap(),
+            SubmissionOutcome::TooHigh { wait: Some(Duration::from_secs(60)) }
+        );
+        assert_eq!(
+            parse_submission_response("That's not the right answer; your answer is too low. please wait 5 minutes before trying again.").unwrap(),
+            SubmissionOutcome::TooLow { wait: Some(Duration::from_secs(300)) }
+        );
+        assert_eq!(
+            parse_submission_response("That's not the right answer. If you're stuck, make sure you're using the full input data.").unwrap(),
+            SubmissionOutcome::Incorrect { wait: None }
+        );
+        assert_eq!(
+            parse_submission_response("You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 5s left to wait.").unwrap(),
+            SubmissionOutcome::RateLimited { wait: Duration::from_secs(65) }
+        );
+        assert_eq!(
+            parse_submission_response(
+                "You don't seem to be solving the right level.  Did you already complete it?"
+            )
+            .unwrap(),
+            SubmissionOutcome::AlreadySolved
+        );
+        assert!(parse_submission_response("Something else").is_err());
+    }
+}
@@ -0,0 +1,321 @@
+/// Module that keeps a history of benchmark runs in `data/{year}/bench_history.jsonl` (by default) and compares runs against each other.
+/// Every line of the history file is one run, serialized as JSON.
+use std::{
+    fmt::Display,
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    path::PathBuf,
+    process::Command,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::template::{config::config, runner::PartResult};
+use crate::{Day, Year};
+
+#[derive(Debug)]
+pub enum Error {
+    Parser(String),
+    IO(io::Error),
+}
+
+impl From<std::io::Error> for Error {
+    fn from(e: std::io::Error) -> Self {
+        Error::IO(e)
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Parser(e) => write!(f, "could not parse benchmark history: {e}"),
+            Error::IO(e) => write!(f, "could not access benchmark history: {e}"),
+        }
+    }
+}
+
+/// A single timed run of all solutions.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HistoryEntry {
+    /// Short hash of the `HEAD` commit at the time of the run, if available.
+    pub commit: Option<String>,
+    /// Whether the working tree had uncommitted changes.
+    pub dirty: bool,
+    /// Unix timestamp in seconds.
+    pub timestamp: u64,
+    pub parts: Vec<PartTiming>,
+}
+
+/// Mean execution time of a solution part in nanoseconds.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PartTiming {
+    pub day: Day,
+    pub part: u8,
+    pub nanos: u64,
+}
+
+impl HistoryEntry {
+    /// Creates an entry for the current commit from the results of a timed run.
+    #[must_use]
+    pub fn from_results(results: &[PartResult]) -> Self {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |d| d.as_secs());
+
+        Self {
+            commit: git(&["rev-parse", "--short", "HEAD"]),
+            dirty: git(&["status", "--porcelain"]).is_some_and(|s| !s.is_empty()),
+            timestamp,
+            parts: results
+                .iter()
+                .filter(|result| result.success)
+                .map(|result| PartTiming {
+                    day: result.day,
+                    part: result.part,
+                    #[allow(clippy::cast_possible_truncation)]
+                    nanos: result.stats.mean.as_nanos() as u64,
+                })
+                .collect(),
+        }
+    }
+
+    /// The commit of the run, marked `-dirty` if the working tree had uncommitted changes.
+    #[must_use]
+    pub fn label(&self) -> String {
+        let commit = self.commit.as_deref().unwrap_or("unknown");
+        if self.dirty {
+            format!("{commit}-dirty")
+        } else {
+            commit.to_string()
+        }
+    }
+
+    /// Returns the mean time of a part in nanoseconds, if it was part of the run.
+    #[must_use]
+    pub fn get(&self, day: Day, part: u8) -> Option<u64> {
+        self.parts
+            .iter()
+            .find(|timing| timing.day == day && timing.part == part)
+            .map(|timing| timing.nanos)
+    }
+}
+
+/// Runs a git command and returns its trimmed stdout if it succeeded.
+fn git(args: &[&str]) -> Option<String> {
+    let output = Command::new("git").args(args).output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    Some(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+fn parse(s: &str) -> Result<Vec<HistoryEntry>, Error> {
+    s.lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| serde_json::from_str(line).map_err(|e| Error::Parser(e.to_string())))
+        .collect()
+}
+
+#[must_use]
+pub fn get_history_path(year: Year) -> PathBuf {
+    config().data.bench_history_path(year)
+}
+
+/// Reads all runs of a year from the history file, oldest first.
+pub fn read(year: Year) -> Result<Vec<HistoryEntry>, Error> {
+    let path = get_history_path(year);
+    if !path.exists() {
+        return Ok(vec![]);
+    }
+    parse(&fs::read_to_string(path)?)
+}
+
+/// Appends a run to the history file of a year.
+pub fn append(year: Year, entry: &HistoryEntry) -> Result<(), Error> {
+    let line = serde_json::to_string(entry).map_err(|e| Error::Parser(e.to_string()))?;
+    let path = get_history_path(year);
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    writeln!(file, "{line}")?;
+    Ok(())
+}
+
+/// Picks the run to compare the latest run against: the latest earlier run of `baseline` if provided, the previous run otherwise.
+/// `baseline` may be a commit hash prefix or any git revision.
+#[must_use]
+pub fn find_baseline<'a>(
+    history: &'a [HistoryEntry],
+    baseline: Option<&str>,
+) -> Option<&'a HistoryEntry> {
+    let (_, earlier) = history.split_last()?;
+
+    let Some(baseline) = baseline else {
+        return earlier.last();
+    };
+
+    let find = |rev: &str| {
+        earlier.iter().rev().find(|entry| {
+            entry
+                .commit
+                .as_deref()
+                .is_some_and(|c| c.starts_with(rev) || rev.starts_with(c))
+        })
+    };
+
+    find(baseline).or_else(|| find(&git(&["rev-parse", "--short", baseline])?))
+}
+
+/// The change of a part's execution time between two runs.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Comparison<'a> {
+    pub day: Day,
+    pub part: u8,
+    /// The run the part was compared against.
+    pub baseline: &'a HistoryEntry,
+    pub baseline_nanos: u64,
+    pub current_nanos: u64,
+    /// Relative change in percent, positive values mean the part got slower.
+    pub change: f64,
+    pub is_regression: bool,
+}
+
+/// Compares every part of `current` against the latest of `baselines` that contains it, `baselines` are ordered oldest first.
+/// Runs of `all --days` or `all --part` time only some parts, so the latest run is not necessarily the baseline of every part.
+/// Parts without a baseline are left out. A part regressed if it got slower by more than `threshold` percent.
+#[must_use]
+pub fn compare<'a>(
+    current: &HistoryEntry,
+    baselines: &'a [HistoryEntry],
+    threshold: f64,
+) -> Vec<Comparison<'a>> {
+    current
+        .parts
+        .iter()
+        .filter_map(|timing| {
+            let (baseline, baseline_nanos) = baselines
+                .iter()
+                .rev()
+                .find_map(|baseline| Some((baseline, baseline.get(timing.day, timing.part)?)))?;
+            #[allow(clippy::cast_precision_loss)]
+            let change = if baseline_nanos == 0 {
+                0.0
+            } else {
+                (timing.nanos as f64 - baseline_nanos as f64) / baseline_nanos as f64 * 100.0
+            };
+            Some(Comparison {
+                day: timing.day,
+                part: timing.part,
+                baseline,
+                baseline_nanos,
+                current_nanos: timing.nanos,
+                change,
+                is_regression: change > threshold,
+            })
+        })
+        .collect()
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{compare, find_baseline, parse, HistoryEntry, PartTiming};
+    use crate::day;
+
+    fn entry(commit: &str, nanos: &[u64]) -> HistoryEntry {
+        HistoryEntry {
+            commit: Some(commit.into()),
+            dirty: false,
+            timestamp: 0,
+            parts: nanos
+                .iter()
+                .enumerate()
+                .map(|(i, &nanos)| PartTiming {
+                    day: day!(1),
+                    #[allow(clippy::cast_possible_truncation)]
+                    part: i as u8 + 1,
+                    nanos,
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn parses_history() {
+        let history = parse(concat!(
+            r#"{"commit":"abc1234","dirty":false,"timestamp":1,"parts":[{"day":1,"part":1,"nanos":100}]}"#,
+            "\n\n",
+            r#"{"commit":null,"dirty":true,"timestamp":2,"parts":[]}"#,
+            "\n"
+        ))
+        .unwrap();
+        assert_eq!(history.len(), 2);
+        assert_eq!(history[0].get(day!(1), 1), Some(100));
+        assert_eq!(history[1].commit, None);
+        assert!(parse("not json").is_err());
+    }
+
+    #[test]
+    fn baseline_defaults_to_previous_run() {
+        let history = vec![
+            entry("aaaaaaa", &[1]),
+            entry("bbbbbbb", &[2]),
+            entry("ccccccc", &[3]),
+        ];
+        assert_eq!(
+            find_baseline(&history, None).unwrap().commit.as_deref(),
+            Some("bbbbbbb")
+        );
+        assert_eq!(find_baseline(&history[..1], None), None);
+        assert_eq!(find_baseline(&[], None), None);
+    }
+
+    #[test]
+    fn baseline_by_commit_prefix() {
+        let history = vec![
+            entry("aaaaaaa", &[1]),
+            entry("bbbbbbb", &[2]),
+            entry("ccccccc", &[3]),
+        ];
+        assert_eq!(
+            find_baseline(&history, Some("aaa"))
+                .unwrap()
+                .commit
+                .as_deref(),
+            Some("aaaaaaa")
+        );
+        // the latest run is never its own baseline.
+        assert_eq!(find_baseline(&history, Some("ccccccc")), None);
+    }
+
+    #[test]
+    fn flags_regressions_beyond_threshold() {
+        let baseline = entry("aaaaaaa", &[100, 100]);
+        let current = entry("bbbbbbb", &[105, 150, 10]);
+        let baselines = [baseline];
+        let comparisons = compare(&current, &baselines, 10.0);
+
+        assert_eq!(comparisons.len(), 2);
+        assert!((comparisons[0].change - 5.0).abs() < 1e-9);
+        assert!(!comparisons[0].is_regression);
+        assert!((comparisons[1].change - 50.0).abs() < 1e-9);
+        assert!(comparisons[1].is_regression);
+    }
+
+    #[test]
+    fn compares_each_part_against_latest_run_with_it() {
+        // the latest run only timed part 1.
+        let baselines = [entry("aaaaaaa", &[100, 100]), entry("bbbbbbb", &[200])];
+        let current = entry("ccccccc", &[150, 150]);
+        let comparisons = compare(&current, &baselines, 10.0);
+
+        assert_eq!(comparisons.len(), 2);
+        assert_eq!(comparisons[0].baseline.commit.as_deref(), Some("bbbbbbb"));
+        assert!(!comparisons[0].is_regression);
+        assert_eq!(comparisons[1].baseline.commit.as_deref(), Some("aaaaaaa"));
+        assert!(comparisons[1].is_regression);
+
+        assert!(compare(&current, &[], 10.0).is_empty());
+    }
+}
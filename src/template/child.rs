@@ -0,0 +1,148 @@
+/// Runs solutions in child processes: `aoc-runner`, the build with every solution compiled in, or the binary of a single day.
+/// Whatever a solution prints itself is kept apart from the result records, so `--format json` output stays parseable.
+use std::{
+    env,
+    io::{BufRead, BufReader},
+    process::{Command, Stdio},
+};
+
+use crate::template::{
+    output::outln,
+    runner::{DayResult, ParseResult, PartResult, RunOptions},
+    OutputFormat,
+};
+use crate::PuzzleId;
+
+/// A line of output of a child that runs with `--format json`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Record {
+    Parse(ParseResult),
+    Part(PartResult),
+}
+
+fn parse_record(line: &str) -> Option<Record> {
+    if !line.starts_with('{') {
+        return None;
+    }
+    // part records have a `part`, parse records do not.
+    serde_json::from_str(line)
+        .map(Record::Part)
+        .or_else(|_| serde_json::from_str(line).map(Record::Parse))
+        .ok()
+}
+
+/// Children are built with the profile of the current binary, so they are as optimized as their parent.
+/// With `mem`, they are built with the counting allocator of `--mem`.
+fn cargo(args: &[&str], mem: bool) -> Command {
+    let mut cmd = Command::new("cargo");
+    cmd.args(args);
+    if !cfg!(debug_assertions) {
+        cmd.arg("--release");
+    }
+    if mem {
+        cmd.args(["--features", "mem"]);
+    }
+    cmd
+}
+
+/// Runs the current command in `aoc-runner` and returns its exit code, [`None`] if the runner does not build.
+/// With `mem`, the runner is built with the counting allocator.
+/// With `--format json`, only result records are passed on to stdout, anything else goes to stderr.
+#[must_use]
+pub fn run_runner(format: OutputFormat, mem: bool) -> Option<i32> {
+    let build = cargo(&["build", "--quiet", "--bin", "aoc-runner"], mem)
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .status();
+
+    if !build.is_ok_and(|status| status.success()) {
+        eprintln!("Not every solution compiles, running the binary of each day on its own.");
+        return None;
+    }
+
+    let mut cmd = cargo(&["run", "--quiet", "--bin", "aoc-runner"], mem);
+    cmd.arg("--").args(env::args().skip(1));
+
+    if format == OutputFormat::Text {
+        return Some(cmd.status().ok()?.code().unwrap_or(1));
+    }
+
+    let mut child = cmd.stdout(Stdio::piped()).spawn().ok()?;
+    let stdout = BufReader::new(child.stdout.take()?);
+
+    for line in stdout.lines().map_while(Result::ok) {
+        if parse_record(&line).is_some() {
+            println!("{line}");
+        } else {
+            eprintln!("{line}");
+        }
+    }
+
+    Some(child.wait().ok()?.code().unwrap_or(1))
+}
+
+/// Runs the binary of a day in its own process and collects its results, [`None`] if it did not run.
+/// Results are not printed, output of the solution itself is passed on: to stdout in text format, to stderr in json format.
+/// Unlike in-process runs, both parts always run, `options.part` only filters the results.
+#[must_use]
+pub fn run_bin(puzzle: PuzzleId, options: RunOptions) -> Option<DayResult> {
+    let bin = puzzle.to_string();
+    let timeout = options
+        .timeout
+        .map_or(0, |timeout| timeout.as_secs().max(1))
+        .to_string();
+
+    let mut cmd = cargo(&["run", "--quiet", "--bin", &bin], options.is_mem);
+    cmd.args(["--", "--format", "json", "--timeout", &timeout]);
+
+    if options.is_timed {
+        cmd.arg("--time");
+    }
+
+    if options.is_mem {
+        cmd.arg("--mem");
+    }
+
+    let mut child = cmd.stdout(Stdio::piped()).spawn().ok()?;
+    let stdout = BufReader::new(child.stdout.take()?);
+
+    let mut result = DayResult {
+        parse: None,
+        parts: vec![],
+    };
+
+    for line in stdout.lines().map_while(Result::ok) {
+        match parse_record(&line) {
+            Some(Record::Parse(parse)) => result.parse = Some(parse),
+            Some(Record::Part(part)) if options.includes_part(part.part) => result.parts.push(part),
+            Some(Record::Part(_)) => {}
+            None => match options.format {
+                OutputFormat::Text => outln!("{line}"),
+                OutputFormat::Json => eprintln!("{line}"),
+            },
+        }
+    }
+
+    let _ = child.wait();
+
+    (result.parse.is_some() || !result.parts.is_empty()).then_some(result)
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{parse_record, Record};
+
+    #[test]
+    fn tells_records_from_other_output() {
+        let part = r#"{"year":2023,"day":1,"part":2,"answer":"42","success":true,"stats":{"mean":19,"median":19,"min":19,"max":19,"std_dev":0,"p95":19,"p99":19,"outliers":0,"samples":1},"expected":null,"correct":null}"#;
+        let parse = r#"{"year":2023,"day":1,"stats":{"mean":19,"median":19,"min":19,"max":19,"std_dev":0,"p95":19,"p99":19,"outliers":0,"samples":1}}"#;
+
+        assert!(matches!(parse_record(part), Some(Record::Part(record)) if record.part == 2));
+        assert!(
+            matches!(parse_record(parse), Some(Record::Parse(record)) if record.error.is_none())
+        );
+        assert_eq!(parse_record("{debug output}"), None);
+        assert_eq!(parse_record("42"), None);
+        assert_eq!(parse_record(""), None);
+    }
+}
@@ -1,40 +1,142 @@
-use std::io;
+use std::{
+    collections::BTreeMap,
+    path::Path,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        mpsc,
+    },
+    thread,
+};
 
 use crate::template::{
+    alloc::MemStats,
+    bench_history::{self, HistoryEntry},
+    child, get_bin_path,
+    output::{self, outln},
     readme_benchmarks::{self, Timings},
-    ANSI_BOLD, ANSI_ITALIC, ANSI_RESET,
+    registry,
+    report::{self, DayReport, Report, ReportFormat},
+    runner::{self, DayResult, ParseResult, PartResult, RunOptions},
+    try_read_file, OutputFormat, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET,
 };
-use crate::{all_days, Day};
-
-pub fn handle(is_release: bool, is_timed: bool) {
+use crate::{Day, DaySelection, PuzzleId, Year};
+
+/// Runs every day of a year in-process, or only the `selection` of days. A part that panics or exceeds the time limit of `options` is reported, the remaining days still run.
+///
+/// With `jobs > 1`, days run concurrently. Their output is buffered and printed in day order.
+/// Timed and memory-profiled runs always run one day at a time, so that days do not skew each other's figures.
+///
+/// A timed run writes its benchmarks to `report` as well, if passed. Nothing is recorded once a part timed out, see [`runner::has_timed_out`].
+pub fn handle(
+    year: Year,
+    is_release: bool,
+    options: RunOptions,
+    jobs: usize,
+    selection: Option<&DaySelection>,
+    report: Option<(ReportFormat, &Path)>,
+) {
     let mut timings: Vec<Timings> = vec![];
+    let mut day_reports: Vec<DayReport> = vec![];
+    let mut all_results: Vec<PartResult> = vec![];
+    let RunOptions {
+        is_timed, format, ..
+    } = options;
+
+    let jobs = if jobs > 1 && (is_timed || options.is_mem) {
+        eprintln!("Ignoring --jobs, timed and memory-profiled runs are not parallelized.");
+        1
+    } else {
+        jobs.max(1)
+    };
 
-    all_days().for_each(|day| {
-        if day > 1 {
-            println!();
-        }
+    if report.is_some() && !is_timed {
+        eprintln!("Ignoring --report, reports are only written by timed runs.");
+    }
 
-        println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
-        println!("------");
+    if let Some(day) = selection.and_then(|x| x.days().find(|day| *day > year.day_count())) {
+        eprintln!(
+            "Ignoring day {day}, {year} only has {} days.",
+            year.day_count()
+        );
+    }
 
-        let output = child_commands::run_solution(day, is_timed, is_release).unwrap();
+    let days: Vec<Day> = year
+        .days()
+        .filter(|day| selection.is_none_or(|x| x.contains(*day)))
+        .collect();
+
+    let mut collect = |result: Option<DayResult>, day: Day| {
+        if let Some(result) = result {
+            timings.push(get_timings(result.parse.as_ref(), &result.parts, day));
+            day_reports.push(DayReport::from_results(
+                day,
+                result.parse.as_ref(),
+                &result.parts,
+            ));
+            all_results.extend(result.parts);
+        }
+    };
 
-        if output.is_empty() {
-            println!("Not solved.");
-        } else {
-            let val = child_commands::parse_exec_time(&output, day);
-            timings.push(val);
+    if jobs == 1 {
+        for &day in &days {
+            collect(run_day(year, &days, day, options), day);
         }
-    });
+    } else {
+        run_parallel(year, &days, options, jobs, |day, result, output| {
+            print!("{output}");
+            collect(result, day);
+        });
+    }
 
     if is_timed {
         let total_millis = timings.iter().map(|x| x.total_nanos).sum::<f64>() / 1_000_000_f64;
 
-        println!("\n{ANSI_BOLD}Total:{ANSI_RESET} {ANSI_ITALIC}{total_millis:.2}ms{ANSI_RESET}");
+        if format == OutputFormat::Text {
+            println!(
+                "\n{ANSI_BOLD}Total:{ANSI_RESET} {ANSI_ITALIC}{total_millis:.2}ms{ANSI_RESET}"
+            );
+        }
+
+        if runner::has_timed_out() {
+            eprintln!("Not recording benchmarks, a part timed out and skewed the figures of the parts after it.");
+            return;
+        }
+
+        if let Some((report_format, path)) = report {
+            let report = Report {
+                year,
+                days: day_reports,
+            };
+            match report::write(&report, report_format, path) {
+                Ok(()) if format == OutputFormat::Text => {
+                    println!("Wrote {report_format} report to {}.", path.display());
+                }
+                Ok(()) => eprintln!("Wrote {report_format} report to {}.", path.display()),
+                Err(e) => eprintln!("Failed to write report: {e}"),
+            }
+        }
 
         if is_release {
-            match readme_benchmarks::update(timings, total_millis) {
-                Ok(()) => println!("Successfully updated README with benchmarks."),
+            if let Err(e) = bench_history::append(year, &HistoryEntry::from_results(&all_results)) {
+                eprintln!("Failed to append benchmarks to history: {e}");
+            }
+
+            // a partial run only replaces the rows and columns it re-timed.
+            let update = if selection.is_some() || options.part.is_some() {
+                readme_benchmarks::update_partial(year, timings, options.part)
+            } else {
+                readme_benchmarks::update(year, timings, total_millis)
+            };
+
+            match update {
+                Ok(()) => {
+                    // keep stdout parseable as NDJSON.
+                    if format == OutputFormat::Text {
+                        println!("Successfully updated README with benchmarks.");
+                    } else {
+                        eprintln!("Successfully updated README with benchmarks.");
+                    }
+                }
                 Err(_) => {
                     eprintln!("Failed to update readme with benchmarks.");
                 }
@@ -43,212 +145,295 @@ pub fn handle(is_release: bool, is_timed: bool) {
     }
 }
 
-#[derive(Debug)]
-pub enum Error {
-    BrokenPipe,
-    Parser(String),
-    IO(io::Error),
+/// Runs `jobs` days at a time, calling `report` with each day's result and buffered output in day order.
+fn run_parallel(
+    year: Year,
+    days: &[Day],
+    options: RunOptions,
+    jobs: usize,
+    mut report: impl FnMut(Day, Option<DayResult>, String),
+) {
+    let next = AtomicUsize::new(0);
+    let (tx, rx) = mpsc::channel();
+
+    thread::scope(|scope| {
+        for _ in 0..jobs.min(days.len()) {
+            let tx = tx.clone();
+            let next = &next;
+            scope.spawn(move || {
+                while let Some(&day) = days.get(next.fetch_add(1, Ordering::Relaxed)) {
+                    let (result, output) = output::capture(|| run_day(year, days, day, options));
+                    if tx.send((day, result, output)).is_err() {
+                        break;
+                    }
+                }
+            });
+        }
+        drop(tx);
+
+        // days finish out of order, hold them back until all previous days are reported.
+        let mut pending = BTreeMap::new();
+        let mut next_day = days.iter().peekable();
+
+        for (day, result, output) in rx {
+            pending.insert(day, (result, output));
+            while let Some((day, (result, output))) =
+                next_day.peek().and_then(|day| pending.remove_entry(*day))
+            {
+                report(day, result, output);
+                next_day.next();
+            }
+        }
+    });
 }
 
-impl From<std::io::Error> for Error {
-    fn from(e: std::io::Error) -> Self {
-        Error::IO(e)
-    }
-}
+/// Prints the header of a day and runs it. `days` are all days of this run.
+fn run_day(year: Year, days: &[Day], day: Day, options: RunOptions) -> Option<DayResult> {
+    let puzzle = PuzzleId::__new_unchecked(year, day);
 
-#[must_use]
-pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
-}
+    if options.format == OutputFormat::Text {
+        if days.first() != Some(&day) {
+            outln!();
+        }
 
-/// All solutions live in isolated binaries.
-/// This module encapsulates interaction with these binaries, both invoking them as well as parsing the timing output.
-mod child_commands {
-    use super::{get_path_for_bin, Error};
-    use crate::Day;
-    use std::{
-        io::{BufRead, BufReader},
-        path::Path,
-        process::{Command, Stdio},
-        thread,
-    };
+        outln!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
+        outln!("------");
+    }
 
-    /// Run the solution bin for a given day
-    pub fn run_solution(day: Day, is_timed: bool, is_release: bool) -> Result<Vec<String>, Error> {
-        // skip command invocation for days that have not been scaffolded yet.
-        if !Path::new(&get_path_for_bin(day)).exists() {
-            return Ok(vec![]);
-        }
+    run_solution(puzzle, options)
+}
 
-        let day_padded = day.to_string();
-        let mut args = vec!["run", "--quiet", "--bin", &day_padded];
+/// Run both parts of a day, in-process if solutions are registered, otherwise in the binary of the day, see [`registry`].
+/// Returns no results for days without solution or input.
+fn run_solution(puzzle: PuzzleId, options: RunOptions) -> Option<DayResult> {
+    let solution = registry::get_solution(puzzle);
 
-        if is_release {
-            args.push("--release");
+    if solution.is_none() && (registry::is_registered() || !get_bin_path(puzzle).exists()) {
+        if options.format == OutputFormat::Text {
+            outln!("Not solved.");
         }
+        return None;
+    }
 
-        if is_timed {
-            // mirror `--time` flag to child invocations.
-            args.push("--");
-            args.push("--time");
+    let Ok(input) = try_read_file("inputs", puzzle) else {
+        if options.format == OutputFormat::Text {
+            outln!("Input missing.");
+        } else {
+            eprintln!("Input for {puzzle} missing.");
         }
+        return None;
+    };
 
-        // spawn child command with piped stdout/stderr.
-        // forward output to stdout/stderr while grabbing stdout lines.
-
-        let mut cmd = Command::new("cargo")
-            .args(&args)
-            .stdout(Stdio::piped())
-            .stderr(Stdio::piped())
-            .spawn()?;
+    if let Some(solution) = solution {
+        return Some((solution.run)(input.leak(), options));
+    }
 
-        let stdout = BufReader::new(cmd.stdout.take().ok_or(super::Error::BrokenPipe)?);
-        let stderr = BufReader::new(cmd.stderr.take().ok_or(super::Error::BrokenPipe)?);
+    let Some(result) = child::run_bin(puzzle, options) else {
+        if options.format == OutputFormat::Text {
+            outln!("Did not run.");
+        } else {
+            eprintln!("{puzzle} did not run.");
+        }
+        return None;
+    };
 
-        let mut output = vec![];
+    if let Some(parse) = &result.parse {
+        runner::print_parse_record(parse, options.format);
+    }
+    for part in &result.parts {
+        runner::print_part_record(part, options.format);
+    }
 
-        let thread = thread::spawn(move || {
-            stderr.lines().for_each(|line| {
-                eprintln!("{}", line.unwrap());
-            });
-        });
+    Some(result)
+}
 
-        for line in stdout.lines() {
-            let line = line.unwrap();
-            println!("{line}");
-            output.push(line);
-        }
+fn add_memory(timings: &mut Timings, mem: Option<MemStats>) {
+    if let Some(mem) = mem {
+        let memory = timings.memory.get_or_insert_with(MemStats::default);
+        memory.allocations += mem.allocations;
+        memory.bytes += mem.bytes;
+        memory.peak_bytes = memory.peak_bytes.max(mem.peak_bytes);
+    }
+}
 
-        thread.join().unwrap();
-        cmd.wait()?;
+fn get_timings(parse: Option<&ParseResult>, results: &[PartResult], day: Day) -> Timings {
+    let mut timings = Timings {
+        day,
+        parse: None,
+        part_1: None,
+        part_2: None,
+        total_nanos: 0_f64,
+        memory: None,
+    };
 
-        Ok(output)
+    if let Some(parse) = parse.filter(|parse| parse.error.is_none()) {
+        timings.parse = Some(format!("{:.1?}", parse.stats.mean));
+        #[allow(clippy::cast_precision_loss)]
+        let nanos = parse.stats.mean.as_nanos() as f64;
+        timings.total_nanos += nanos;
+        add_memory(&mut timings, parse.mem);
     }
 
-    pub fn parse_exec_time(output: &[String], day: Day) -> super::Timings {
-        let mut timings = super::Timings {
-            day,
-            part_1: None,
-            part_2: None,
-            total_nanos: 0_f64,
-        };
+    results
+        .iter()
+        .filter(|result| result.success)
+        .for_each(|result| {
+            let timing_str = format!("{:.1?}", result.stats.mean);
 
-        output
-            .iter()
-            .filter_map(|l| {
-                if !l.contains(" samples)") {
-                    return None;
-                }
+            match result.part {
+                1 => timings.part_1 = Some(timing_str),
+                2 => timings.part_2 = Some(timing_str),
+                _ => {}
+            }
 
-                let Some((timing_str, nanos)) = parse_time(l) else {
-                    eprintln!("Could not parse timings from line: {l}");
-                    return None;
-                };
+            #[allow(clippy::cast_precision_loss)]
+            let nanos = result.stats.mean.as_nanos() as f64;
+            timings.total_nanos += nanos;
+            add_memory(&mut timings, result.mem);
+        });
 
-                let part = l.split(':').next()?;
-                Some((part, timing_str, nanos))
-            })
-            .for_each(|(part, timing_str, nanos)| {
-                if part.contains("Part 1") {
-                    timings.part_1 = Some(timing_str.into());
-                } else if part.contains("Part 2") {
-                    timings.part_2 = Some(timing_str.into());
-                }
+    timings
+}
 
-                timings.total_nanos += nanos;
-            });
+/// copied from: https://github.com/rust-lang/rust/blob/1.64.0/library/std/src/macros.rs#L328-L333
+#[cfg(feature = "test_lib")]
+macro_rules! assert_approx_eq {
+    ($a:expr, $b:expr) => {{
+        let (a, b) = (&$a, &$b);
+        assert!(
+            (*a - *b).abs() < 1.0e-6,
+            "{} is not approximately equal to {}",
+            *a,
+            *b
+        );
+    }};
+}
 
-        timings
-    }
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::get_timings;
+    use std::time::Duration;
 
-    fn parse_to_float(s: &str, postfix: &str) -> Option<f64> {
-        s.split(postfix).next()?.parse().ok()
+    use crate::template::{
+        alloc::MemStats,
+        runner::{ParseResult, PartResult, Stats},
+    };
+    use crate::{day, year};
+
+    fn mock_result(part: u8, answer: Option<&str>, mean_nanos: u64) -> PartResult {
+        let mean = Duration::from_nanos(mean_nanos);
+        PartResult {
+            year: year!(2023),
+            day: day!(1),
+            part,
+            answer: answer.map(Into::into),
+            success: answer.is_some(),
+            stats: Stats {
+                mean,
+                median: mean,
+                min: mean,
+                max: mean,
+                std_dev: Duration::ZERO,
+                p95: mean,
+                p99: mean,
+                outliers: 0,
+                samples: 100,
+            },
+            expected: None,
+            correct: None,
+            mem: None,
+            error: None,
+        }
     }
 
-    fn parse_time(line: &str) -> Option<(&str, f64)> {
-        // for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
-        let str_timing = line
-            .split(" samples)")
-            .next()?
-            .split('(')
-            .last()?
-            .split('@')
-            .next()?
-            .trim();
-
-        let parsed_timing = match str_timing {
-            s if s.contains("ns") => s.split("ns").next()?.parse::<f64>().ok(),
-            s if s.contains("µs") => parse_to_float(s, "µs").map(|x| x * 1000_f64),
-            s if s.contains("ms") => parse_to_float(s, "ms").map(|x| x * 1_000_000_f64),
-            s => parse_to_float(s, "s").map(|x| x * 1_000_000_000_f64),
-        }?;
-
-        Some((str_timing, parsed_timing))
+    #[test]
+    fn test_well_formed() {
+        let res = get_timings(
+            None,
+            &[
+                mock_result(1, Some("0"), 74),
+                mock_result(2, Some("10"), 74_130_000),
+            ],
+            day!(1),
+        );
+        assert_approx_eq!(res.total_nanos, 74130074_f64);
+        assert_eq!(res.part_1.unwrap(), "74.0ns");
+        assert_eq!(res.part_2.unwrap(), "74.1ms");
     }
 
-    /// copied from: https://github.com/rust-lang/rust/blob/1.64.0/library/std/src/macros.rs#L328-L333
-    #[cfg(feature = "test_lib")]
-    macro_rules! assert_approx_eq {
-        ($a:expr, $b:expr) => {{
-            let (a, b) = (&$a, &$b);
-            assert!(
-                (*a - *b).abs() < 1.0e-6,
-                "{} is not approximately equal to {}",
-                *a,
-                *b
-            );
-        }};
+    #[test]
+    fn test_single_part() {
+        let res = get_timings(
+            None,
+            &[mock_result(1, Some("(@) samples)"), 2_000_000_000)],
+            day!(1),
+        );
+        assert_approx_eq!(res.total_nanos, 2000000000_f64);
+        assert_eq!(res.part_1.unwrap(), "2.0s");
+        assert_eq!(res.part_2.is_none(), true);
     }
 
-    #[cfg(feature = "test_lib")]
-    mod tests {
-        use super::parse_exec_time;
-
-        use crate::day;
+    #[test]
+    fn test_parse() {
+        let parse = ParseResult {
+            year: year!(2023),
+            day: day!(1),
+            stats: Stats::single(Duration::from_micros(5)),
+            mem: None,
+            error: None,
+        };
+        let res = get_timings(
+            Some(&parse),
+            &[
+                mock_result(1, Some("0"), 1_000),
+                mock_result(2, Some("10"), 2_000),
+            ],
+            day!(1),
+        );
+        assert_approx_eq!(res.total_nanos, 8000_f64);
+        assert_eq!(res.parse.unwrap(), "5.0µs");
+        assert_eq!(res.part_1.unwrap(), "1.0µs");
+    }
 
-        #[test]
-        fn test_well_formed() {
-            let res = parse_exec_time(
-                &[
-                    "Part 1: 0 (74.13ns @ 100000 samples)".into(),
-                    "Part 2: 10 (74.13ms @ 99999 samples)".into(),
-                    "".into(),
-                ],
-                day!(1),
-            );
-            assert_approx_eq!(res.total_nanos, 74130074.13_f64);
-            assert_eq!(res.part_1.unwrap(), "74.13ns");
-            assert_eq!(res.part_2.unwrap(), "74.13ms");
-        }
+    #[test]
+    fn test_memory() {
+        let mut part_1 = mock_result(1, Some("1"), 10);
+        part_1.mem = Some(MemStats {
+            allocations: 2,
+            bytes: 300,
+            peak_bytes: 200,
+        });
+        let mut part_2 = mock_result(2, Some("2"), 10);
+        part_2.mem = Some(MemStats {
+            allocations: 3,
+            bytes: 500,
+            peak_bytes: 100,
+        });
 
-        #[test]
-        fn test_patterns_in_input() {
-            let res = parse_exec_time(
-                &[
-                    "Part 1: @ @ @ ( ) ms (2s @ 5 samples)".into(),
-                    "Part 2: 10s (100ms @ 1 samples)".into(),
-                    "".into(),
-                ],
-                day!(1),
-            );
-            assert_approx_eq!(res.total_nanos, 2100000000_f64);
-            assert_eq!(res.part_1.unwrap(), "2s");
-            assert_eq!(res.part_2.unwrap(), "100ms");
-        }
+        let res = get_timings(None, &[part_1, part_2], day!(1));
+        assert_eq!(
+            res.memory,
+            Some(MemStats {
+                allocations: 5,
+                bytes: 800,
+                peak_bytes: 200,
+            })
+        );
+        assert_eq!(
+            get_timings(None, &[mock_result(1, Some("1"), 10)], day!(1)).memory,
+            None
+        );
+    }
 
-        #[test]
-        fn test_missing_parts() {
-            let res = parse_exec_time(
-                &[
-                    "Part 1: ✖        ".into(),
-                    "Part 2: ✖        ".into(),
-                    "".into(),
-                ],
-                day!(1),
-            );
-            assert_approx_eq!(res.total_nanos, 0_f64);
-            assert_eq!(res.part_1.is_none(), true);
-            assert_eq!(res.part_2.is_none(), true);
-        }
+    #[test]
+    fn test_missing_parts() {
+        let res = get_timings(
+            None,
+            &[mock_result(1, None, 10), mock_result(2, None, 10)],
+            day!(1),
+        );
+        assert_approx_eq!(res.total_nanos, 0_f64);
+        assert_eq!(res.part_1.is_none(), true);
+        assert_eq!(res.part_2.is_none(), true);
     }
 }
@@ -0,0 +1,87 @@
+use std::{process, ptr, slice, time::Duration};
+
+use crate::template::{bench_history, ANSI_BOLD, ANSI_RESET};
+use crate::Year;
+
+/// Compares the latest benchmark run against a baseline run.
+/// Without `baseline`, each part is compared against the latest earlier run that timed it, see [`bench_history::compare`].
+/// Exits with a non-zero status if any part regressed beyond `threshold` percent, or if no part could be compared.
+pub fn handle(year: Year, baseline: Option<&str>, threshold: f64) {
+    let history = match bench_history::read(year) {
+        Ok(history) => history,
+        Err(e) => {
+            eprintln!("{e}");
+            process::exit(1);
+        }
+    };
+
+    let Some((current, earlier)) = history.split_last() else {
+        eprintln!("No benchmark history found for {year}. Run `cargo time` first.");
+        process::exit(1);
+    };
+
+    let Some(baseline_entry) = bench_history::find_baseline(&history, baseline) else {
+        match baseline {
+            Some(baseline) => eprintln!("No benchmark run found for baseline \"{baseline}\"."),
+            None => eprintln!("No previous benchmark run to compare against."),
+        }
+        process::exit(1);
+    };
+
+    let baselines = if baseline.is_some() {
+        slice::from_ref(baseline_entry)
+    } else {
+        earlier
+    };
+
+    println!(
+        "Comparing {ANSI_BOLD}{}{ANSI_RESET} against {ANSI_BOLD}{}{ANSI_RESET} (threshold: {threshold}%)",
+        current.label(),
+        baseline_entry.label()
+    );
+    println!();
+
+    let comparisons = bench_history::compare(current, baselines, threshold);
+
+    for comparison in &comparisons {
+        // parts that the previous run did not time are compared against an older run.
+        let older = if ptr::eq(comparison.baseline, baseline_entry) {
+            String::new()
+        } else {
+            format!(" (against {})", comparison.baseline.label())
+        };
+
+        println!(
+            "Day {} Part {}: {:>9} → {:>9} ({:+.1}%){}{older}",
+            comparison.day,
+            comparison.part,
+            format!("{:.1?}", Duration::from_nanos(comparison.baseline_nanos)),
+            format!("{:.1?}", Duration::from_nanos(comparison.current_nanos)),
+            comparison.change,
+            if comparison.is_regression {
+                " ✘ regression"
+            } else {
+                ""
+            }
+        );
+    }
+
+    if comparisons.is_empty() {
+        eprintln!("No part of the latest run was timed in the baseline, nothing to compare.");
+        process::exit(1);
+    }
+
+    let regressions = comparisons.iter().filter(|c| c.is_regression).count();
+    let skipped = current.parts.len() - comparisons.len();
+
+    println!();
+    println!("{ANSI_BOLD}Regressions:{ANSI_RESET} {regressions}");
+
+    if skipped > 0 {
+        println!("{skipped} part(s) were not timed in the baseline and are not compared.");
+    }
+
+    if regressions > 0 {
+        process::exit(1);
+    }
+}
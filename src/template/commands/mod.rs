@@ -1,5 +1,10 @@
 pub mod all;
+pub mod bench_diff;
 pub mod download;
+pub mod progress;
 pub mod read;
 pub mod scaffold;
 pub mod solve;
+pub mod status;
+pub mod verify;
+pub mod watch;
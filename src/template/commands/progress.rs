@@ -0,0 +1,163 @@
+use std::{
+    collections::HashMap,
+    process::{self, Command, Stdio},
+};
+
+use crate::template::{
+    answers, get_bin_path,
+    readme_progress::{self, DayProgress, PartStatus},
+    submissions, ANSI_BOLD, ANSI_RESET,
+};
+use crate::{Day, PuzzleId, Year};
+
+/// Updates the progress table of a year in the readme. Runs the example tests of every scaffolded day first.
+pub fn handle(year: Year) {
+    let scaffolded: Vec<PuzzleId> = year
+        .days()
+        .map(|day| PuzzleId::__new_unchecked(year, day))
+        .filter(|puzzle| get_bin_path(*puzzle).exists())
+        .collect();
+
+    println!("Running example tests of {} days...", scaffolded.len());
+    let examples = run_example_tests(&scaffolded);
+
+    let progress: Vec<DayProgress> = year
+        .days()
+        .map(|day| {
+            let puzzle = PuzzleId::__new_unchecked(year, day);
+            get_progress(puzzle, examples.get(&day).copied())
+        })
+        .collect();
+
+    for day in progress.iter().filter(|day| day.is_scaffolded) {
+        let example = match day.example {
+            Some(true) => "✔",
+            Some(false) => "✘",
+            None => "-",
+        };
+        let stars = match day.stars() {
+            0 => "no stars".into(),
+            n => "⭐".repeat(n),
+        };
+        println!("Day {}: example {example}, {stars}", day.day);
+    }
+
+    let stars: usize = progress.iter().map(DayProgress::stars).sum();
+
+    match readme_progress::update(year, &progress) {
+        Ok(()) => println!(
+            "{ANSI_BOLD}{stars} stars.{ANSI_RESET} Successfully updated README with progress."
+        ),
+        Err(_) => {
+            eprintln!("Failed to update readme with progress.");
+            process::exit(1);
+        }
+    }
+}
+
+fn get_progress(puzzle: PuzzleId, example: Option<bool>) -> DayProgress {
+    let answers = answers::read(puzzle).unwrap_or_else(|e| {
+        eprintln!("Day {}: {e}", puzzle.day);
+        answers::Answers::default()
+    });
+
+    let log = submissions::read(puzzle).unwrap_or_else(|e| {
+        eprintln!("Day {}: {e}", puzzle.day);
+        vec![]
+    });
+
+    DayProgress {
+        day: puzzle.day,
+        is_scaffolded: get_bin_path(puzzle).exists(),
+        example,
+        part_1: PartStatus::from_records(&answers, &log, 1),
+        part_2: PartStatus::from_records(&answers, &log, 2),
+    }
+}
+
+/// Builds the test binaries of the given days in one cargo invocation, then runs each of them.
+/// Days whose tests could not be built are left out.
+fn run_example_tests(puzzles: &[PuzzleId]) -> HashMap<Day, bool> {
+    let mut results = HashMap::new();
+
+    if puzzles.is_empty() {
+        return results;
+    }
+
+    let mut cmd = Command::new("cargo");
+    cmd.args(["test", "--no-run", "--quiet", "--message-format=json"]);
+    for puzzle in puzzles {
+        cmd.args(["--bin", &puzzle.to_string()]);
+    }
+
+    let output = match cmd.stderr(Stdio::inherit()).output() {
+        Ok(output) => output,
+        Err(e) => {
+            eprintln!("Failed to run cargo: {e}");
+            return results;
+        }
+    };
+
+    // cargo stops at the first day that fails to build, the days built until then can still be run.
+    if !output.status.success() {
+        eprintln!("Failed to build the example tests of some days.");
+    }
+
+    for (name, executable) in get_test_executables(&String::from_utf8_lossy(&output.stdout)) {
+        let Some(puzzle) = puzzles.iter().find(|puzzle| puzzle.to_string() == name) else {
+            continue;
+        };
+
+        let passed = Command::new(&executable)
+            .arg("--quiet")
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .status()
+            .is_ok_and(|status| status.success());
+
+        results.insert(puzzle.day, passed);
+    }
+
+    results
+}
+
+/// Reads the name and path of every test binary from cargo's JSON messages.
+fn get_test_executables(messages: &str) -> Vec<(String, String)> {
+    messages
+        .lines()
+        .filter_map(|line| serde_json::from_str::<serde_json::Value>(line).ok())
+        .filter(|message| {
+            message["reason"] == "compiler-artifact" && message["profile"]["test"] == true
+        })
+        .filter_map(|message| {
+            Some((
+                message["target"]["name"].as_str()?.to_string(),
+                message["executable"].as_str()?.to_string(),
+            ))
+        })
+        .collect()
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::get_test_executables;
+
+    #[test]
+    fn reads_test_executables() {
+        let messages = [
+            r#"{"reason":"compiler-artifact","target":{"name":"2023-01","kind":["bin"]},"profile":{"test":true},"executable":"/target/debug/deps/2023_01-abc"}"#,
+            r#"{"reason":"compiler-artifact","target":{"name":"advent_of_code","kind":["lib"]},"profile":{"test":false},"executable":null}"#,
+            r#"{"reason":"build-finished","success":true}"#,
+            "not json",
+        ]
+        .join("\n");
+
+        assert_eq!(
+            get_test_executables(&messages),
+            [(
+                "2023-01".to_string(),
+                "/target/debug/deps/2023_01-abc".to_string()
+            )]
+        );
+    }
+}
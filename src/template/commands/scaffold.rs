@@ -1,12 +1,14 @@
 use std::{
-    fs::{File, OpenOptions},
+    fs::{self, File, OpenOptions},
     io::Write,
+    path::Path,
     process,
 };
 
-use crate::Day;
+use crate::template::{example, get_bin_path, get_data_path};
+use crate::PuzzleId;
 
-const MODULE_TEMPLATE: &str = r#"advent_of_code::solution!(DAY_NUMBER);
+const MODULE_TEMPLATE: &str = r#"advent_of_code::solution!(YEAR_NUMBER, DAY_NUMBER);
 
 pub fn part_one(input: &str) -> Option<u32> {
     None
@@ -22,30 +24,67 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, None);
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, None);
     }
 }
 "#;
 
-fn safe_create_file(path: &str) -> Result<File, std::io::Error> {
+fn create_parent_dir(path: &Path) -> Result<(), std::io::Error> {
+    match path.parent() {
+        Some(dir) => fs::create_dir_all(dir),
+        None => Ok(()),
+    }
+}
+
+fn safe_create_file(path: &Path) -> Result<File, std::io::Error> {
+    create_parent_dir(path)?;
     OpenOptions::new().write(true).create_new(true).open(path)
 }
 
-fn create_file(path: &str) -> Result<File, std::io::Error> {
-    OpenOptions::new().write(true).create(true).open(path)
+fn create_file(path: &Path) -> Result<File, std::io::Error> {
+    create_parent_dir(path)?;
+    OpenOptions::new()
+        .write(true)
+        .create(true)
+        .truncate(false)
+        .open(path)
+}
+
+/// Binaries in nested directories are not discovered by cargo, so every solution gets a `[[bin]]` entry.
+/// Returns `false` if the manifest already contains the entry.
+fn add_bin_target(puzzle: PuzzleId, module_path: &Path) -> Result<bool, std::io::Error> {
+    let manifest = fs::read_to_string("Cargo.toml")?;
+    let name = format!("name = \"{puzzle}\"");
+
+    if manifest.lines().any(|line| line.trim() == name) {
+        return Ok(false);
+    }
+
+    let mut file = OpenOptions::new().append(true).open("Cargo.toml")?;
+    write!(
+        file,
+        "{}[[bin]]\n{name}\npath = \"{}\"\n",
+        if manifest.ends_with('\n') {
+            "\n"
+        } else {
+            "\n\n"
+        },
+        module_path.display().to_string().replace('\\', "/")
+    )?;
+    Ok(true)
 }
 
-pub fn handle(day: Day) {
-    let input_path = format!("data/inputs/{day}.txt");
-    let example_path = format!("data/examples/{day}.txt");
-    let module_path = format!("src/bin/{day}.rs");
+pub fn handle(puzzle: PuzzleId) {
+    let input_path = get_data_path("inputs", puzzle);
+    let example_path = get_data_path("examples", puzzle);
+    let module_path = get_bin_path(puzzle);
 
     let mut file = match safe_create_file(&module_path) {
         Ok(file) => file,
@@ -57,11 +96,12 @@ pub fn handle(day: Day) {
 
     match file.write_all(
         MODULE_TEMPLATE
-            .replace("DAY_NUMBER", &day.into_inner().to_string())
+            .replace("YEAR_NUMBER", &puzzle.year.to_string())
+            .replace("DAY_NUMBER", &puzzle.day.into_inner().to_string())
             .as_bytes(),
     ) {
         Ok(()) => {
-            println!("Created module file \"{}\"", &module_path);
+            println!("Created module file \"{}\"", module_path.display());
         }
         Err(e) => {
             eprintln!("Failed to write module contents: {e}");
@@ -71,7 +111,7 @@ pub fn handle(day: Day) {
 
     match create_file(&input_path) {
         Ok(_) => {
-            println!("Created empty input file \"{}\"", &input_path);
+            println!("Created empty input file \"{}\"", input_path.display());
         }
         Err(e) => {
             eprintln!("Failed to create input file: {e}");
@@ -81,7 +121,7 @@ pub fn handle(day: Day) {
 
     match create_file(&example_path) {
         Ok(_) => {
-            println!("Created empty example file \"{}\"", &example_path);
+            println!("Created empty example file \"{}\"", example_path.display());
         }
         Err(e) => {
             eprintln!("Failed to create example file: {e}");
@@ -89,6 +129,24 @@ pub fn handle(day: Day) {
         }
     }
 
+    match add_bin_target(puzzle, &module_path) {
+        Ok(true) => {
+            println!("Added binary \"{puzzle}\" to \"Cargo.toml\"");
+        }
+        Ok(false) => {}
+        Err(e) => {
+            eprintln!("Failed to add binary to Cargo.toml: {e}");
+            process::exit(1);
+        }
+    }
+
+    if let Err(e) = example::apply(puzzle) {
+        eprintln!("Failed to extract example: {e}");
+    }
+
     println!("---");
-    println!("🎄 Type `cargo solve {}` to run your solution.", day);
+    println!(
+        "🎄 Type `cargo solve {} --year {}` to run your solution.",
+        puzzle.day, puzzle.year
+    );
 }
@@ -1,14 +1,28 @@
 use std::process::{Command, Stdio};
 
-use crate::Day;
-
-pub fn handle(day: Day, release: bool, time: bool, submit_part: Option<u8>) {
-    let mut cmd_args = vec!["run".to_string(), "--bin".to_string(), day.to_string()];
+use crate::template::OutputFormat;
+use crate::PuzzleId;
+
+pub fn handle(
+    puzzle: PuzzleId,
+    release: bool,
+    time: bool,
+    mem: bool,
+    submit_part: Option<u8>,
+    format: OutputFormat,
+    timeout: Option<u64>,
+) {
+    let mut cmd_args = vec!["run".to_string(), "--bin".to_string(), puzzle.to_string()];
 
     if release {
         cmd_args.push("--release".to_string());
     }
 
+    if mem {
+        cmd_args.push("--features".to_string());
+        cmd_args.push("mem".to_string());
+    }
+
     cmd_args.push("--".to_string());
 
     if let Some(submit_part) = submit_part {
@@ -20,6 +34,20 @@ pub fn handle(day: Day, release: bool, time: bool, submit_part: Option<u8>) {
         cmd_args.push("--time".to_string());
     }
 
+    if mem {
+        cmd_args.push("--mem".to_string());
+    }
+
+    if format != OutputFormat::Text {
+        cmd_args.push("--format".to_string());
+        cmd_args.push(format.to_string());
+    }
+
+    if let Some(timeout) = timeout {
+        cmd_args.push("--timeout".to_string());
+        cmd_args.push(timeout.to_string());
+    }
+
     let mut cmd = Command::new("cargo")
         .args(&cmd_args)
         .stdout(Stdio::inherit())
@@ -0,0 +1,291 @@
+use std::{fs, path::Path, time::Duration};
+
+use crate::template::{
+    answers::{self, Answers},
+    bench_history::{self, HistoryEntry},
+    get_bin_path, get_data_path,
+    submissions::{self, Submission, Verdict},
+    ANSI_BOLD, ANSI_RESET,
+};
+use crate::{Day, PuzzleId, Year};
+
+/// Whether a file of a day exists and has content.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum FileState {
+    Missing,
+    /// The file exists, but contains only whitespace.
+    Empty,
+    Present,
+}
+
+impl FileState {
+    fn of(path: &Path) -> Self {
+        match fs::read_to_string(path) {
+            Ok(content) if content.trim().is_empty() => FileState::Empty,
+            Ok(_) => FileState::Present,
+            Err(_) => FileState::Missing,
+        }
+    }
+
+    fn symbol(self) -> &'static str {
+        match self {
+            FileState::Missing => "✘",
+            FileState::Empty => "empty",
+            FileState::Present => "✔",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct PartStatus {
+    answer: Option<String>,
+    /// Mean time of the part in the latest benchmark run that timed it.
+    nanos: Option<u64>,
+    last_verdict: Option<Verdict>,
+    wrong: usize,
+}
+
+impl PartStatus {
+    fn new(
+        answers: &Answers,
+        log: &[Submission],
+        history: &[HistoryEntry],
+        day: Day,
+        part: u8,
+    ) -> Self {
+        let submissions: Vec<&Submission> = log
+            .iter()
+            .filter(|submission| submission.part == part)
+            .collect();
+
+        Self {
+            answer: answers.get(part).map(Into::into),
+            nanos: history.iter().rev().find_map(|entry| entry.get(day, part)),
+            last_verdict: submissions.last().map(|submission| submission.verdict),
+            wrong: submissions
+                .iter()
+                .filter(|submission| submission.verdict.is_wrong())
+                .count(),
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.answer.is_none() && self.nanos.is_none() && self.last_verdict.is_none()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct DayStatus {
+    day: Day,
+    bin: FileState,
+    example: FileState,
+    input: FileState,
+    puzzle: FileState,
+    /// Number of tests that still assert the `None` of the scaffold.
+    placeholders: usize,
+    part_1: PartStatus,
+    part_2: PartStatus,
+}
+
+/// Counts the tests that were not updated since the day was scaffolded.
+fn count_placeholders(source: &str) -> usize {
+    source
+        .lines()
+        .filter(|line| line.trim() == "assert_eq!(result, None);")
+        .count()
+}
+
+fn get_status(puzzle: PuzzleId, history: &[HistoryEntry]) -> DayStatus {
+    let answers = answers::read(puzzle).unwrap_or_else(|e| {
+        eprintln!("Day {}: {e}", puzzle.day);
+        Answers::default()
+    });
+
+    let log = submissions::read(puzzle).unwrap_or_else(|e| {
+        eprintln!("Day {}: {e}", puzzle.day);
+        vec![]
+    });
+
+    let bin_path = get_bin_path(puzzle);
+
+    DayStatus {
+        day: puzzle.day,
+        bin: FileState::of(&bin_path),
+        example: FileState::of(&get_data_path("examples", puzzle)),
+        input: FileState::of(&get_data_path("inputs", puzzle)),
+        puzzle: FileState::of(&get_data_path("puzzles", puzzle)),
+        placeholders: fs::read_to_string(&bin_path).map_or(0, |source| count_placeholders(&source)),
+        part_1: PartStatus::new(&answers, &log, history, puzzle.day, 1),
+        part_2: PartStatus::new(&answers, &log, history, puzzle.day, 2),
+    }
+}
+
+fn format_part(part: u8, status: &PartStatus) -> String {
+    let mut line = format!(
+        "    Part {part}: {}",
+        status.answer.as_deref().unwrap_or("-")
+    );
+
+    if let Some(nanos) = status.nanos {
+        line.push_str(&format!(" in {:.1?}", Duration::from_nanos(nanos)));
+    }
+
+    if let Some(verdict) = status.last_verdict {
+        line.push_str(&format!(", last submission {verdict}"));
+    }
+
+    if status.wrong > 0 {
+        line.push_str(&format!(" ({} wrong)", status.wrong));
+    }
+
+    line
+}
+
+fn format_day(status: &DayStatus) -> Vec<String> {
+    let is_started = [status.bin, status.example, status.input, status.puzzle]
+        .iter()
+        .any(|state| *state != FileState::Missing)
+        || !status.part_1.is_empty()
+        || !status.part_2.is_empty();
+
+    if !is_started {
+        return vec![format!("Day {}: not started", status.day)];
+    }
+
+    let tests = match (status.bin, status.placeholders) {
+        (FileState::Missing, _) => "-".into(),
+        (_, 0) => "✔".into(),
+        (_, 1) => "1 placeholder".into(),
+        (_, n) => format!("{n} placeholders"),
+    };
+
+    let mut lines = vec![format!(
+        "Day {}: bin {}, example {}, input {}, puzzle {}, tests {tests}",
+        status.day,
+        status.bin.symbol(),
+        status.example.symbol(),
+        status.input.symbol(),
+        status.puzzle.symbol()
+    )];
+
+    for (part, part_status) in [(1, &status.part_1), (2, &status.part_2)] {
+        if !part_status.is_empty() {
+            lines.push(format_part(part, part_status));
+        }
+    }
+
+    lines
+}
+
+/// Prints an overview of every day of a year: which files exist, whether the tests were written,
+/// and the last known answers, timings and submission verdicts. Only local files are read.
+pub fn handle(year: Year) {
+    let history = bench_history::read(year).unwrap_or_else(|e| {
+        eprintln!("{e}");
+        vec![]
+    });
+
+    let statuses: Vec<DayStatus> = year
+        .days()
+        .map(|day| get_status(PuzzleId::__new_unchecked(year, day), &history))
+        .collect();
+
+    for status in &statuses {
+        for line in format_day(status) {
+            println!("{line}");
+        }
+    }
+
+    let scaffolded = statuses
+        .iter()
+        .filter(|status| status.bin != FileState::Missing)
+        .count();
+    let answered: usize = statuses
+        .iter()
+        .map(|status| {
+            [&status.part_1, &status.part_2]
+                .iter()
+                .filter(|part| part.answer.is_some())
+                .count()
+        })
+        .sum();
+
+    println!();
+    println!(
+        "{ANSI_BOLD}{year}:{ANSI_RESET} {scaffolded} of {} days scaffolded, {answered} of {} answers stored.",
+        year.day_count(),
+        usize::from(year.day_count()) * 2
+    );
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{count_placeholders, format_day, DayStatus, FileState, PartStatus};
+    use crate::day;
+    use crate::template::submissions::Verdict;
+
+    fn part(
+        answer: Option<&str>,
+        nanos: Option<u64>,
+        last_verdict: Option<Verdict>,
+        wrong: usize,
+    ) -> PartStatus {
+        PartStatus {
+            answer: answer.map(Into::into),
+            nanos,
+            last_verdict,
+            wrong,
+        }
+    }
+
+    #[test]
+    fn counts_scaffold_placeholders() {
+        let source = [
+            "fn test_part_one() {",
+            "    let result = part_one(&input);",
+            "    assert_eq!(result, Some(42));",
+            "}",
+            "fn test_part_two() {",
+            "    let result = part_two(&input);",
+            "    assert_eq!(result, None);",
+            "}",
+        ]
+        .join("\n");
+        assert_eq!(count_placeholders(&source), 1);
+    }
+
+    #[test]
+    fn format_day_status() {
+        let status = DayStatus {
+            day: day!(1),
+            bin: FileState::Present,
+            example: FileState::Empty,
+            input: FileState::Present,
+            puzzle: FileState::Missing,
+            placeholders: 2,
+            part_1: part(Some("42"), Some(1_500), Some(Verdict::Correct), 1),
+            part_2: part(None, None, Some(Verdict::TooLow), 2),
+        };
+
+        assert_eq!(
+            format_day(&status),
+            [
+                "Day 01: bin ✔, example empty, input ✔, puzzle ✘, tests 2 placeholders",
+                "    Part 1: 42 in 1.5µs, last submission correct (1 wrong)",
+                "    Part 2: -, last submission too low (2 wrong)",
+            ]
+        );
+
+        let status = DayStatus {
+            day: day!(2),
+            bin: FileState::Missing,
+            example: FileState::Missing,
+            input: FileState::Missing,
+            puzzle: FileState::Missing,
+            placeholders: 0,
+            part_1: part(None, None, None, 0),
+            part_2: part(None, None, None, 0),
+        };
+        assert_eq!(format_day(&status), ["Day 02: not started"]);
+    }
+}
@@ -0,0 +1,136 @@
+use std::process;
+
+use crate::template::{
+    answers, child,
+    config::config,
+    get_bin_path,
+    registry::{self, Solution},
+    runner::{self, DayResult, RunOptions},
+    try_read_file, ANSI_BOLD, ANSI_RESET,
+};
+use crate::{PuzzleId, Year};
+
+/// Runs every implemented day of a year against its input and checks the results against the stored answers.
+/// A part that panics or exceeds the configured time limit is reported as not matching, the remaining days still run.
+/// Days run in-process if solutions are registered, otherwise in their own binary, see [`registry`].
+/// Exits with a non-zero status if any result does not match.
+pub fn handle(year: Year) {
+    let mut checked = 0;
+    let mut correct = 0;
+    let mut has_errors = false;
+
+    for day in year.days() {
+        let puzzle = PuzzleId::__new_unchecked(year, day);
+        let solution = registry::get_solution(puzzle);
+
+        if solution.is_none() && (registry::is_registered() || !get_bin_path(puzzle).exists()) {
+            continue;
+        }
+
+        let answers = match answers::read(puzzle) {
+            Ok(answers) => answers,
+            Err(e) => {
+                eprintln!("Day {day}: {e}");
+                has_errors = true;
+                continue;
+            }
+        };
+
+        if answers.part_1.is_none() && answers.part_2.is_none() {
+            println!("Day {day}: no stored answers.");
+            continue;
+        }
+
+        let Ok(input) = try_read_file("inputs", puzzle) else {
+            println!("Day {day}: input missing.");
+            continue;
+        };
+
+        let mut line = format!("Day {day}:");
+        let mut bin_result = None;
+
+        for part in [1, 2] {
+            let Some(expected) = answers.get(part) else {
+                line.push_str(&format!(" Part {part} -"));
+                continue;
+            };
+
+            let result = match solution {
+                Some(solution) => solve_in_process(solution, part, input.clone()),
+                None => {
+                    let bin_result = bin_result.get_or_insert_with(|| solve_in_bin(puzzle));
+                    part_result(bin_result.as_ref(), part)
+                }
+            };
+            checked += 1;
+
+            let result = match result {
+                Ok(result) => result,
+                Err(e) => {
+                    line.push_str(&format!(" Part {part} ✘ ({e})"));
+                    continue;
+                }
+            };
+
+            if answers.check(part, result.as_deref()) == Some(true) {
+                correct += 1;
+                line.push_str(&format!(" Part {part} ✔"));
+            } else {
+                line.push_str(&format!(
+                    " Part {part} ✘ (got {}, expected {expected})",
+                    result.as_deref().unwrap_or("✖")
+                ));
+            }
+        }
+
+        println!("{line}");
+    }
+
+    println!();
+    println!("{ANSI_BOLD}Verified:{ANSI_RESET} {correct} of {checked} answers correct.");
+
+    if has_errors || correct < checked {
+        process::exit(1);
+    }
+}
+
+fn solve_in_process(
+    solution: &Solution,
+    part: u8,
+    input: String,
+) -> Result<Option<String>, String> {
+    let func = if part == 1 {
+        solution.part_one
+    } else {
+        solution.part_two
+    };
+    runner::run_isolated(move || func(&input), config().run.timeout(None))
+        .map_err(|e| e.to_string())
+}
+
+fn solve_in_bin(puzzle: PuzzleId) -> Option<DayResult> {
+    let options = RunOptions {
+        timeout: config().run.timeout(None),
+        ..RunOptions::default()
+    };
+    child::run_bin(puzzle, options)
+}
+
+/// The answer of a part run in the binary of its day, or why there is none.
+fn part_result(result: Option<&DayResult>, part: u8) -> Result<Option<String>, String> {
+    let Some(result) = result else {
+        return Err("did not run".into());
+    };
+
+    match result.parts.iter().find(|record| record.part == part) {
+        Some(record) => match &record.error {
+            Some(e) => Err(e.clone()),
+            None => Ok(record.answer.clone()),
+        },
+        None => Err(result
+            .parse
+            .as_ref()
+            .and_then(|parse| parse.error.clone())
+            .map_or_else(|| "did not run".into(), |e| format!("parse {e}"))),
+    }
+}
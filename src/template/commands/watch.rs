@@ -0,0 +1,234 @@
+use std::{
+    fs,
+    path::PathBuf,
+    process::{self, Command, Output},
+    thread,
+    time::{Duration, SystemTime},
+};
+
+use crate::template::{
+    get_bin_path, get_data_path,
+    runner::{format_check, PartResult},
+    ANSI_BOLD, ANSI_ITALIC, ANSI_RESET,
+};
+use crate::PuzzleId;
+
+const POLL_INTERVAL: Duration = Duration::from_millis(300);
+
+/// Editors often write a file in several steps, wait for them to finish before re-running.
+const DEBOUNCE: Duration = Duration::from_millis(100);
+
+/// Re-runs the example tests and the real input of a day whenever its solution or data files change.
+/// `timeout` is passed on to the solution, see [`crate::template::runner::RunOptions::timeout`].
+pub fn handle(puzzle: PuzzleId, release: bool, timeout: Option<u64>) {
+    let files = watched_files(puzzle);
+
+    println!("{ANSI_BOLD}Watching {puzzle}{ANSI_RESET}, press Ctrl+C to stop.");
+    for file in &files {
+        println!("  {}", file.display());
+    }
+
+    let mut last_snapshot = None;
+    let mut previous: Vec<PartResult> = vec![];
+
+    loop {
+        let mut snapshot = get_snapshot(&files);
+
+        if last_snapshot.as_ref() != Some(&snapshot) {
+            if last_snapshot.is_some() {
+                thread::sleep(DEBOUNCE);
+                snapshot = get_snapshot(&files);
+            }
+            last_snapshot = Some(snapshot);
+
+            println!();
+            println!("{ANSI_BOLD}{puzzle}{ANSI_RESET}");
+            println!("------");
+
+            if let Some(results) = run_once(puzzle, release, timeout, &previous) {
+                previous = results;
+            }
+        }
+
+        thread::sleep(POLL_INTERVAL);
+    }
+}
+
+fn watched_files(puzzle: PuzzleId) -> Vec<PathBuf> {
+    vec![
+        get_bin_path(puzzle),
+        get_data_path("examples", puzzle),
+        get_data_path("inputs", puzzle),
+        get_data_path("answers", puzzle),
+    ]
+}
+
+/// Modification times of the watched files, [`None`] for files that do not exist (yet).
+fn get_snapshot(files: &[PathBuf]) -> Vec<Option<SystemTime>> {
+    files
+        .iter()
+        .map(|file| fs::metadata(file).and_then(|m| m.modified()).ok())
+        .collect()
+}
+
+/// Runs a cargo command. `bin_args` are passed on to the binary after `--`, cargo's own flags go before it.
+fn cargo(args: &[&str], release: bool, bin_args: &[&str]) -> Output {
+    let mut cmd = Command::new("cargo");
+    cmd.args(args);
+
+    if release {
+        cmd.arg("--release");
+    }
+
+    if !bin_args.is_empty() {
+        cmd.arg("--").args(bin_args);
+    }
+
+    match cmd.output() {
+        Ok(output) => output,
+        Err(e) => {
+            eprintln!("Failed to run cargo: {e}");
+            process::exit(1);
+        }
+    }
+}
+
+/// Builds the day, runs its tests and its solution. Returns the results of the solution if it ran.
+fn run_once(
+    puzzle: PuzzleId,
+    release: bool,
+    timeout: Option<u64>,
+    previous: &[PartResult],
+) -> Option<Vec<PartResult>> {
+    let bin = puzzle.to_string();
+
+    let build = cargo(&["build", "--quiet", "--bin", &bin], release, &[]);
+    if !build.status.success() {
+        eprint!("{}", String::from_utf8_lossy(&build.stderr));
+        println!("{ANSI_ITALIC}Build failed.{ANSI_RESET}");
+        return None;
+    }
+
+    let test = cargo(&["test", "--quiet", "--bin", &bin], release, &[]);
+    let test_output = String::from_utf8_lossy(&test.stdout);
+    let summary = test_output
+        .lines()
+        .find_map(|line| line.strip_prefix("test result: "))
+        .unwrap_or("no tests ran");
+
+    if test.status.success() {
+        println!("Tests: ✔ {summary}");
+    } else {
+        print!("{test_output}");
+        eprint!("{}", String::from_utf8_lossy(&test.stderr));
+        println!("Tests: ✘ {summary}");
+    }
+
+    let timeout = timeout.map(|secs| secs.to_string());
+    let mut bin_args = vec!["--format", "json"];
+    if let Some(timeout) = &timeout {
+        bin_args.extend(["--timeout", timeout]);
+    }
+
+    let run = cargo(&["run", "--quiet", "--bin", &bin], release, &bin_args);
+    eprint!("{}", String::from_utf8_lossy(&run.stderr));
+
+    // solutions may print debug output, only keep the result records.
+    let results: Vec<PartResult> = String::from_utf8_lossy(&run.stdout)
+        .lines()
+        .filter_map(|line| serde_json::from_str(line).ok())
+        .collect();
+
+    if results.is_empty() {
+        println!("{ANSI_ITALIC}Solution did not run.{ANSI_RESET}");
+        return None;
+    }
+
+    for result in &results {
+        let previous = previous.iter().find(|x| x.part == result.part);
+        println!("{}", format_change(result, previous));
+    }
+
+    Some(results)
+}
+
+fn format_answer(answer: Option<&str>) -> String {
+    match answer {
+        Some(answer) if answer.contains('\n') => "▼ (multi-line)".into(),
+        Some(answer) => answer.into(),
+        None => "✖".into(),
+    }
+}
+
+/// Formats a part's result along with how it changed since the previous run and whether it matches the stored answer.
+fn format_change(result: &PartResult, previous: Option<&PartResult>) -> String {
+    let change = match previous {
+        None => String::new(),
+        Some(previous) if previous.answer == result.answer => " (unchanged)".into(),
+        Some(previous) => format!(" (was {})", format_answer(previous.answer.as_deref())),
+    };
+
+    format!(
+        "Part {}: {ANSI_BOLD}{}{ANSI_RESET}{change}{}",
+        result.part,
+        format_answer(result.answer.as_deref()),
+        format_check(result)
+    )
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::format_change;
+    use crate::puzzle;
+    use crate::template::{
+        runner::{PartResult, Stats},
+        ANSI_BOLD, ANSI_RESET,
+    };
+    use std::time::Duration;
+
+    fn result(answer: Option<&str>, expected: Option<&str>) -> PartResult {
+        let puzzle = puzzle!(2023, 6);
+        PartResult {
+            year: puzzle.year,
+            day: puzzle.day,
+            part: 1,
+            answer: answer.map(Into::into),
+            success: answer.is_some(),
+            stats: Stats::single(Duration::from_micros(5)),
+            expected: expected.map(Into::into),
+            correct: expected.map(|expected| answer == Some(expected)),
+            mem: None,
+            error: None,
+        }
+    }
+
+    #[test]
+    fn formats_first_run() {
+        assert_eq!(
+            format_change(&result(Some("288"), None), None),
+            format!("Part 1: {ANSI_BOLD}288{ANSI_RESET}")
+        );
+    }
+
+    #[test]
+    fn formats_unchanged_answer() {
+        let previous = result(Some("288"), Some("288"));
+        assert_eq!(
+            format_change(&result(Some("288"), Some("288")), Some(&previous)),
+            format!("Part 1: {ANSI_BOLD}288{ANSI_RESET} (unchanged) ✔")
+        );
+    }
+
+    #[test]
+    fn formats_changed_answer() {
+        let previous = result(Some("288"), Some("288"));
+        assert_eq!(
+            format_change(&result(Some("300"), Some("288")), Some(&previous)),
+            format!("Part 1: {ANSI_BOLD}300{ANSI_RESET} (was 288) ✘ (expected 288)")
+        );
+        assert_eq!(
+            format_change(&result(None, None), Some(&previous)),
+            format!("Part 1: {ANSI_BOLD}✖{ANSI_RESET} (was 288)")
+        );
+    }
+}
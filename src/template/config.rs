@@ -0,0 +1,395 @@
+/// Module that reads the project configuration from `aoc.toml`.
+/// Every setting is optional, a missing file or key falls back to the defaults below.
+use std::{
+    env,
+    fmt::Display,
+    fs, io,
+    path::{Path, PathBuf},
+    process,
+    sync::OnceLock,
+    time::Duration,
+};
+
+use serde::Deserialize;
+
+use crate::{PuzzleId, Year};
+
+pub const CONFIG_PATH: &str = "aoc.toml";
+
+#[derive(Debug)]
+pub enum Error {
+    Parser(String),
+    IO(io::Error),
+}
+
+impl From<std::io::Error> for Error {
+    fn from(e: std::io::Error) -> Self {
+        Error::IO(e)
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Parser(e) => write!(f, "could not parse {CONFIG_PATH}: {e}"),
+            Error::IO(e) => write!(f, "could not read {CONFIG_PATH}: {e}"),
+        }
+    }
+}
+
+/// Typed contents of `aoc.toml`.
+///
+/// ```toml
+/// year = 2023
+///
+/// [data]
+/// dir = "data"
+/// inputs = "{year}/inputs/{day}.txt"
+///
+/// [readme]
+/// path = "README.md"
+///
+/// [readme.table]
+/// sort = "time"
+///
+/// [bench]
+/// budget_ms = 1000
+///
+/// [session]
+/// file = "~/.adventofcode.session"
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    /// Year used by commands when neither `--year` nor `AOC_YEAR` is set.
+    pub year: Option<Year>,
+    pub data: DataConfig,
+    pub readme: ReadmeConfig,
+    pub bench: BenchConfig,
+    pub run: RunConfig,
+    pub session: SessionConfig,
+}
+
+/// Layout of the data directory.
+/// Paths are relative to `dir`, `{year}` and `{day}` are replaced with the puzzle's year and zero-padded day.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct DataConfig {
+    pub dir: PathBuf,
+    pub inputs: String,
+    pub examples: String,
+    pub puzzles: String,
+    pub answers: String,
+    pub submissions: String,
+    /// Only `{year}` is replaced in this path.
+    pub bench_history: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ReadmeConfig {
+    pub path: PathBuf,
+    /// Label of the benchmark table markers, rendered as `<!--- {marker} {year} --->`.
+    pub marker: String,
+    /// Label of the progress table markers, rendered like `marker`.
+    pub progress_marker: String,
+    pub table: TableConfig,
+}
+
+/// Layout of the benchmark table. The optional columns are off by default.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct TableConfig {
+    /// Adds a column with the combined time of each day.
+    pub total: bool,
+    /// Adds a column with each day's share of the total time.
+    pub share: bool,
+    /// Adds a column with the change of each day's combined time versus the previous table.
+    pub delta: bool,
+    pub sort: TableSort,
+    /// Number of slowest days to highlight, `0` disables highlighting.
+    pub highlight: usize,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TableSort {
+    #[default]
+    Day,
+    /// Slowest day first.
+    Time,
+}
+
+/// Sample budget of `--time`: a part runs as often as fits into `budget_ms`, within `min_samples..=max_samples`.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct BenchConfig {
+    pub budget_ms: u64,
+    pub min_samples: u128,
+    pub max_samples: u128,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct RunConfig {
+    /// Time limit of the first run of a part or parse stage in seconds, `0` disables the limit.
+    pub timeout_secs: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct SessionConfig {
+    /// File that contains the session cookie. A leading `~` is expanded to the home directory.
+    /// The `AOC_SESSION` environment variable takes precedence.
+    pub file: String,
+}
+
+impl Default for DataConfig {
+    fn default() -> Self {
+        Self {
+            dir: PathBuf::from("data"),
+            inputs: "{year}/inputs/{day}.txt".into(),
+            examples: "{year}/examples/{day}.txt".into(),
+            puzzles: "{year}/puzzles/{day}.md".into(),
+            answers: "{year}/answers/{day}.toml".into(),
+            submissions: "{year}/submissions/{day}.jsonl".into(),
+            bench_history: "{year}/bench_history.jsonl".into(),
+        }
+    }
+}
+
+impl Default for ReadmeConfig {
+    fn default() -> Self {
+        Self {
+            path: PathBuf::from("README.md"),
+            marker: "benchmarking table".into(),
+            progress_marker: "progress table".into(),
+            table: TableConfig::default(),
+        }
+    }
+}
+
+impl Default for BenchConfig {
+    fn default() -> Self {
+        Self {
+            budget_ms: 1000,
+            min_samples: 10,
+            max_samples: 10000,
+        }
+    }
+}
+
+impl Default for RunConfig {
+    fn default() -> Self {
+        Self { timeout_secs: 60 }
+    }
+}
+
+impl Default for SessionConfig {
+    fn default() -> Self {
+        Self {
+            file: "~/.adventofcode.session".into(),
+        }
+    }
+}
+
+impl DataConfig {
+    /// Returns the path of a puzzle's file in a data folder, e.g. `data/2023/inputs/01.txt` for `inputs`.
+    /// Folders without a configured layout resolve to `{year}/{folder}/{day}.txt`.
+    #[must_use]
+    pub fn path(&self, folder: &str, puzzle: PuzzleId) -> PathBuf {
+        let template = match folder {
+            "inputs" => self.inputs.clone(),
+            "examples" => self.examples.clone(),
+            "puzzles" => self.puzzles.clone(),
+            "answers" => self.answers.clone(),
+            "submissions" => self.submissions.clone(),
+            x => format!("{{year}}/{x}/{{day}}.txt"),
+        };
+
+        self.dir.join(
+            template
+                .replace("{year}", &puzzle.year.to_string())
+                .replace("{day}", &puzzle.day.to_string()),
+        )
+    }
+
+    #[must_use]
+    pub fn bench_history_path(&self, year: Year) -> PathBuf {
+        self.dir
+            .join(self.bench_history.replace("{year}", &year.to_string()))
+    }
+}
+
+impl ReadmeConfig {
+    /// Every year has its own table, delimited by a pair of markers.
+    #[must_use]
+    pub fn marker(&self, year: Year) -> String {
+        format!("<!--- {} {year} --->", self.marker)
+    }
+
+    #[must_use]
+    pub fn progress_marker(&self, year: Year) -> String {
+        format!("<!--- {} {year} --->", self.progress_marker)
+    }
+}
+
+impl BenchConfig {
+    /// Number of samples to take of a part whose first run took `base_time`.
+    #[must_use]
+    pub fn samples(&self, base_time: Duration) -> u128 {
+        let budget = Duration::from_millis(self.budget_ms).as_nanos();
+        (budget / base_time.as_nanos().max(10))
+            .clamp(self.min_samples, self.max_samples.max(self.min_samples))
+    }
+}
+
+impl RunConfig {
+    /// The time limit to apply, `secs` overrides the configured limit if passed.
+    #[must_use]
+    pub fn timeout(&self, secs: Option<u64>) -> Option<Duration> {
+        match secs.unwrap_or(self.timeout_secs) {
+            0 => None,
+            secs => Some(Duration::from_secs(secs)),
+        }
+    }
+}
+
+impl SessionConfig {
+    #[must_use]
+    pub fn path(&self) -> Option<PathBuf> {
+        match self.file.strip_prefix("~/") {
+            Some(rest) => {
+                let home = env::var_os("HOME").or_else(|| env::var_os("USERPROFILE"))?;
+                Some(PathBuf::from(home).join(rest))
+            }
+            None => Some(PathBuf::from(&self.file)),
+        }
+    }
+}
+
+impl Config {
+    /// Reads a config file, falling back to the defaults if it does not exist.
+    pub fn load(path: &Path) -> Result<Self, Error> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        parse(&fs::read_to_string(path)?)
+    }
+
+    /// The year to work on if none is passed: `AOC_YEAR` if set, `year` of the config file otherwise.
+    #[must_use]
+    pub fn default_year(&self) -> Option<Year> {
+        Year::from_env().or(self.year)
+    }
+}
+
+fn parse(s: &str) -> Result<Config, Error> {
+    toml::from_str(s).map_err(|e| Error::Parser(e.to_string()))
+}
+
+/// The configuration of the current project, read from `aoc.toml` on first access.
+/// Exits the process if the file exists but can not be read.
+pub fn config() -> &'static Config {
+    static CONFIG: OnceLock<Config> = OnceLock::new();
+    CONFIG.get_or_init(|| match Config::load(Path::new(CONFIG_PATH)) {
+        Ok(config) => config,
+        Err(e) => {
+            eprintln!("{e}");
+            process::exit(1);
+        }
+    })
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{parse, Config, TableSort};
+    use crate::{puzzle, year};
+    use std::{path::PathBuf, time::Duration};
+
+    #[test]
+    fn defaults_for_empty_file() {
+        let config = parse("").unwrap();
+        assert_eq!(config, Config::default());
+        assert_eq!(
+            config.data.path("inputs", puzzle!(2023, 1)),
+            PathBuf::from("data/2023/inputs/01.txt")
+        );
+        assert_eq!(
+            config.data.bench_history_path(year!(2023)),
+            PathBuf::from("data/2023/bench_history.jsonl")
+        );
+        assert_eq!(
+            config.readme.marker(year!(2023)),
+            "<!--- benchmarking table 2023 --->"
+        );
+        assert_eq!(
+            config.readme.progress_marker(year!(2023)),
+            "<!--- progress table 2023 --->"
+        );
+    }
+
+    #[test]
+    fn parses_partial_config() {
+        let config = parse(
+            "year = 2022\n\n[data]\ndir = \"puzzles\"\ninputs = \"in/{year}-{day}.txt\"\n\n[bench]\nmax_samples = 50\n",
+        )
+        .unwrap();
+        assert_eq!(config.year, Some(year!(2022)));
+        assert_eq!(
+            config.data.path("inputs", puzzle!(2022, 7)),
+            PathBuf::from("puzzles/in/2022-07.txt")
+        );
+        assert_eq!(
+            config.data.path("examples", puzzle!(2022, 7)),
+            PathBuf::from("puzzles/2022/examples/07.txt")
+        );
+        assert_eq!(config.bench.min_samples, 10);
+        assert_eq!(config.bench.max_samples, 50);
+    }
+
+    #[test]
+    fn resolves_unknown_data_folders() {
+        let config = Config::default();
+        assert_eq!(
+            config.data.path("examples-2", puzzle!(2023, 12)),
+            PathBuf::from("data/2023/examples-2/12.txt")
+        );
+    }
+
+    #[test]
+    fn parses_table_layout() {
+        let config =
+            parse("[readme.table]\ntotal = true\nsort = \"time\"\nhighlight = 3\n").unwrap();
+        assert!(config.readme.table.total);
+        assert!(!config.readme.table.delta);
+        assert_eq!(config.readme.table.sort, TableSort::Time);
+        assert_eq!(config.readme.table.highlight, 3);
+        assert!(parse("[readme.table]\nsort = \"name\"").is_err());
+    }
+
+    #[test]
+    fn errors_on_invalid_config() {
+        assert!(parse("year = 2001").is_err());
+        assert!(parse("[data]\nunknown = 1").is_err());
+    }
+
+    #[test]
+    fn resolves_timeout() {
+        let config = Config::default();
+        assert_eq!(config.run.timeout(None), Some(Duration::from_secs(60)));
+        assert_eq!(config.run.timeout(Some(5)), Some(Duration::from_secs(5)));
+        assert_eq!(config.run.timeout(Some(0)), None);
+
+        let config = parse("[run]\ntimeout_secs = 0").unwrap();
+        assert_eq!(config.run.timeout(None), None);
+    }
+
+    #[test]
+    fn clamps_bench_samples() {
+        let config = Config::default();
+        assert_eq!(config.bench.samples(Duration::from_secs(2)), 10);
+        assert_eq!(config.bench.samples(Duration::from_millis(10)), 100);
+        assert_eq!(config.bench.samples(Duration::from_nanos(1)), 10000);
+    }
+}
@@ -0,0 +1,274 @@
+/// Module that extracts the example input and its expected answers from a downloaded puzzle description.
+/// The example is written to the examples file and the answers replace the `None` placeholders of the scaffolded tests.
+use std::{fmt::Display, fs, io};
+
+use crate::template::{get_bin_path, get_data_path};
+use crate::PuzzleId;
+
+#[derive(Debug)]
+pub enum Error {
+    Parser(String),
+    IO(io::Error),
+}
+
+impl From<std::io::Error> for Error {
+    fn from(e: std::io::Error) -> Self {
+        Error::IO(e)
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Parser(e) => write!(f, "could not find example in puzzle description: {e}"),
+            Error::IO(e) => write!(f, "could not access example files: {e}"),
+        }
+    }
+}
+
+const PART_TWO_HEADING: &str = "## --- Part Two ---";
+const PLACEHOLDER: &str = "assert_eq!(result, None);";
+
+/// The example of a puzzle and the answers the description gives for it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Example {
+    pub input: String,
+    pub answers: [Option<String>; 2],
+}
+
+/// A fenced code block and the last line of text before it.
+struct CodeBlock<'a> {
+    intro: &'a str,
+    content: String,
+}
+
+fn code_blocks(markdown: &str) -> Vec<CodeBlock<'_>> {
+    let mut blocks = vec![];
+    let mut intro = "";
+    let mut content: Option<String> = None;
+
+    for line in markdown.lines() {
+        match (line.trim() == "```", content.as_mut()) {
+            (true, None) => content = Some(String::new()),
+            (true, Some(_)) => blocks.push(CodeBlock {
+                intro,
+                content: content.take().unwrap_or_default(),
+            }),
+            (false, Some(content)) => {
+                content.push_str(line);
+                content.push('\n');
+            }
+            (false, None) if !line.trim().is_empty() => intro = line,
+            (false, None) => {}
+        }
+    }
+
+    blocks
+}
+
+/// Picks the code block introduced by "For example", the first code block if there is none.
+fn find_example(markdown: &str) -> Option<String> {
+    let blocks = code_blocks(markdown);
+    blocks
+        .iter()
+        .find(|block| block.intro.to_lowercase().contains("for example"))
+        .or_else(|| blocks.first())
+        .map(|block| block.content.clone())
+}
+
+/// The answer of a part is the last emphasized code span of its description, e.g. *`142`*.
+fn find_answer(section: &str) -> Option<String> {
+    let end = section.rfind("`*")?;
+    let start = section[..end].rfind("*`")? + 2;
+    let answer = &section[start..end];
+    (!answer.is_empty() && !answer.contains('\n')).then(|| answer.to_string())
+}
+
+/// Extracts the example and its answers from a puzzle description in Markdown.
+/// The answer of part two is only kept if part two does not introduce an example of its own,
+/// as the tests of both parts read the same example file.
+#[must_use]
+pub fn parse(markdown: &str) -> Option<Example> {
+    let (part_one, part_two) = match markdown.find(PART_TWO_HEADING) {
+        Some(pos) => (&markdown[..pos], Some(&markdown[pos..])),
+        None => (markdown, None),
+    };
+
+    let input = find_example(part_one)?;
+
+    let answer_two = part_two
+        .filter(|section| {
+            code_blocks(section)
+                .iter()
+                .filter(|block| block.intro.to_lowercase().contains("for example"))
+                .all(|block| block.content == input)
+        })
+        .and_then(find_answer);
+
+    Some(Example {
+        answers: [find_answer(part_one), answer_two],
+        input,
+    })
+}
+
+/// Replaces the `None` placeholder of each part's test with the expected answer.
+/// Only numeric answers are filled in, as the scaffolded solutions return integers.
+/// Returns the updated source and the number of filled placeholders.
+#[must_use]
+pub fn fill_tests(source: &str, answers: &[Option<String>; 2]) -> (String, usize) {
+    let mut filled = 0;
+    let mut answer: Option<&str> = None;
+
+    let lines: Vec<String> = source
+        .lines()
+        .map(|line| {
+            if line.contains("fn test_part_one") {
+                answer = answers[0].as_deref();
+            } else if line.contains("fn test_part_two") {
+                answer = answers[1].as_deref();
+            } else if line.contains("fn ") {
+                answer = None;
+            }
+
+            match answer {
+                Some(value) if line.trim() == PLACEHOLDER && value.parse::<u64>().is_ok() => {
+                    answer = None;
+                    filled += 1;
+                    line.replace(PLACEHOLDER, &format!("assert_eq!(result, Some({value}));"))
+                }
+                _ => line.to_string(),
+            }
+        })
+        .collect();
+
+    let mut updated = lines.join("\n");
+    if source.ends_with('\n') {
+        updated.push('\n');
+    }
+    (updated, filled)
+}
+
+/// Writes the example of a day's downloaded puzzle description to the examples file, unless it already has content,
+/// and fills the expected answers into the tests of the day's solution.
+/// Does nothing if the puzzle description was not downloaded yet.
+pub fn apply(puzzle: PuzzleId) -> Result<(), Error> {
+    let Ok(markdown) = fs::read_to_string(get_data_path("puzzles", puzzle)) else {
+        return Ok(());
+    };
+
+    let example = parse(&markdown).ok_or_else(|| Error::Parser("no code block.".into()))?;
+
+    let example_path = get_data_path("examples", puzzle);
+    let is_empty = fs::read_to_string(&example_path).map_or(true, |s| s.trim().is_empty());
+
+    if is_empty {
+        if let Some(dir) = example_path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(&example_path, &example.input)?;
+        println!("Wrote example to \"{}\"", example_path.display());
+    }
+
+    let bin_path = get_bin_path(puzzle);
+    if let Ok(source) = fs::read_to_string(&bin_path) {
+        let (updated, filled) = fill_tests(&source, &example.answers);
+        if filled > 0 {
+            fs::write(&bin_path, updated)?;
+            println!(
+                "Filled {filled} expected answer(s) into the tests of \"{}\"",
+                bin_path.display()
+            );
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{fill_tests, parse, Example};
+
+    const PUZZLE: &str = "## --- Day 1: Trebuchet?! ---
+
+The newly-improved calibration document consists of lines of text.
+
+```
+not the example
+```
+
+For example:
+
+```
+1abc2
+pqr3stu8vwx
+```
+
+In this example, the calibration values of these lines are `12` and `38`. Adding these together produces *`50`*.
+
+Your puzzle answer was `54632`.
+
+## --- Part Two ---
+
+It looks like some of the digits are actually spelled out. Using the same example, the sum is *`50`* again, no, *`281`*.
+";
+
+    #[test]
+    fn parses_example_and_answers() {
+        assert_eq!(
+            parse(PUZZLE),
+            Some(Example {
+                input: "1abc2\npqr3stu8vwx\n".into(),
+                answers: [Some("50".into()), Some("281".into())],
+            })
+        );
+    }
+
+    #[test]
+    fn skips_answer_of_different_example() {
+        let puzzle =
+            format!("{PUZZLE}\nFor example:\n\n```\ntwo1nine\n```\n\nThe sum is *`29`*.\n");
+        let example = parse(&puzzle).unwrap();
+        assert_eq!(example.answers, [Some("50".into()), None]);
+    }
+
+    #[test]
+    fn falls_back_to_first_code_block() {
+        let example = parse("Here is a map:\n\n```\n#.#\n```\n\nIt has *`2`* walls.\n").unwrap();
+        assert_eq!(example.input, "#.#\n");
+        assert_eq!(example.answers, [Some("2".into()), None]);
+        assert_eq!(parse("no code"), None);
+    }
+
+    #[test]
+    fn fills_placeholders() {
+        let source = [
+            "pub fn part_one(input: &str) -> Option<u32> {",
+            "    None",
+            "}",
+            "",
+            "#[test]",
+            "fn test_part_one() {",
+            "    let result = part_one(&advent_of_code::template::read_file(\"examples\", PUZZLE));",
+            "    assert_eq!(result, None);",
+            "}",
+            "",
+            "#[test]",
+            "fn test_part_two() {",
+            "    assert_eq!(result, None);",
+            "}",
+            "",
+        ]
+        .join("\n");
+
+        let (updated, filled) = fill_tests(&source, &[Some("142".into()), Some("ABC".into())]);
+        assert_eq!(filled, 1);
+        assert_eq!(
+            updated,
+            source.replacen(
+                "assert_eq!(result, None);",
+                "assert_eq!(result, Some(142));",
+                1
+            )
+        );
+    }
+}
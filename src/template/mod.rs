@@ -1,36 +1,187 @@
-use crate::Day;
-use std::{env, fs};
+use crate::PuzzleId;
+use std::fmt::Display;
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::{env, fs, io};
 
-pub mod aoc_cli;
+pub mod alloc;
+pub mod answers;
+pub mod aoc_client;
+pub mod bench_history;
+pub mod child;
 pub mod commands;
+pub mod config;
+pub mod example;
+pub mod output;
 pub mod readme_benchmarks;
+pub mod readme_progress;
+pub mod registry;
+pub mod report;
 pub mod runner;
+pub mod submissions;
 
 pub const ANSI_ITALIC: &str = "\x1b[3m";
 pub const ANSI_BOLD: &str = "\x1b[1m";
 pub const ANSI_RESET: &str = "\x1b[0m";
 
+/// Output format of the `solve` and `all` commands.
+///  1. `text` prints human-readable, ANSI-decorated results.
+///  2. `json` prints one JSON record per solution part (NDJSON).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum OutputFormat {
+    #[default]
+    Text,
+    Json,
+}
+
+impl OutputFormat {
+    /// Reads the `--format` argument of the current process, falling back to [`OutputFormat::Text`].
+    #[must_use]
+    pub fn from_args() -> Self {
+        let args: Vec<String> = env::args().collect();
+        args.iter()
+            .position(|x| x == "--format")
+            .and_then(|i| args.get(i + 1))
+            .and_then(|x| x.parse().ok())
+            .unwrap_or_default()
+    }
+}
+
+impl Display for OutputFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            OutputFormat::Text => f.write_str("text"),
+            OutputFormat::Json => f.write_str("json"),
+        }
+    }
+}
+
+impl FromStr for OutputFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(OutputFormat::Text),
+            "json" => Ok(OutputFormat::Json),
+            x => Err(format!(
+                "unknown output format `{x}`, expecting `text` or `json`"
+            )),
+        }
+    }
+}
+
+/// Returns the path of a puzzle's file in a data folder, e.g. `data/2023/inputs/01.txt`.
+/// The layout of the data directory is configured in `aoc.toml`.
+#[must_use]
+pub fn get_data_path(folder: &str, puzzle: PuzzleId) -> PathBuf {
+    config::config().data.path(folder, puzzle)
+}
+
+/// Returns the path of a puzzle's solution, e.g. `src/bin/2023/01.rs`.
+#[must_use]
+pub fn get_bin_path(puzzle: PuzzleId) -> PathBuf {
+    PathBuf::from("src")
+        .join("bin")
+        .join(puzzle.year.to_string())
+        .join(format!("{}.rs", puzzle.day))
+}
+
 /// Helper function that reads a text file to a string.
 #[must_use]
-pub fn read_file(folder: &str, day: Day) -> String {
-    let cwd = env::current_dir().unwrap();
-    let filepath = cwd.join("data").join(folder).join(format!("{day}.txt"));
-    let f = fs::read_to_string(filepath);
-    f.expect("could not open input file")
+pub fn read_file(folder: &str, puzzle: PuzzleId) -> String {
+    try_read_file(folder, puzzle).expect("could not open input file")
 }
 
-/// Creates the constant `DAY` and sets up the input and runner for each part.
+/// Helper function that reads a text file to a string, returning an error if it can not be read.
+pub fn try_read_file(folder: &str, puzzle: PuzzleId) -> io::Result<String> {
+    let cwd = env::current_dir()?;
+    fs::read_to_string(cwd.join(get_data_path(folder, puzzle)))
+}
+
+/// Creates the constant `PUZZLE` and sets up the input and runner for each part.
+/// Also defines `SOLUTION`, the entry of this day in [`crate::template::registry`].
+///
+/// If a parse function is passed as third argument, it runs once and both parts receive a reference to its result:
+///
+/// ```ignore
+/// advent_of_code::solution!(2023, 2, parse);
+///
+/// pub fn parse(input: &str) -> Vec<Game> { /* ... */ }
+/// pub fn part_one(games: &[Game]) -> Option<u32> { /* ... */ }
+/// ```
 #[macro_export]
 macro_rules! solution {
-    ($day:expr) => {
-        /// The current day.
-        const DAY: advent_of_code::Day = advent_of_code::day!($day);
+    ($year:expr, $day:expr) => {
+        /// The current puzzle.
+        const PUZZLE: advent_of_code::PuzzleId = advent_of_code::puzzle!($year, $day);
+
+        /// Entry points of this day, used to run it in-process.
+        #[allow(dead_code)]
+        pub const SOLUTION: advent_of_code::template::registry::Solution =
+            advent_of_code::template::registry::Solution {
+                puzzle: PUZZLE,
+                part_one: |input| part_one(input).map(|x| x.to_string()),
+                part_two: |input| part_two(input).map(|x| x.to_string()),
+                run: |input, options| {
+                    use advent_of_code::template::runner::*;
+                    let mut parts = vec![];
+                    if options.includes_part(1) {
+                        parts.push(run_part_with(part_one, input, PUZZLE, 1, options));
+                    }
+                    if options.includes_part(2) {
+                        parts.push(run_part_with(part_two, input, PUZZLE, 2, options));
+                    }
+                    DayResult { parse: None, parts }
+                },
+            };
+
+        #[allow(dead_code)]
+        fn main() {
+            use advent_of_code::template::runner::*;
+            let input: &'static str = advent_of_code::template::read_file("inputs", PUZZLE).leak();
+            run_part(part_one, input, PUZZLE, 1);
+            run_part(part_two, input, PUZZLE, 2);
+        }
+    };
+    ($year:expr, $day:expr, $parse:ident) => {
+        /// The current puzzle.
+        const PUZZLE: advent_of_code::PuzzleId = advent_of_code::puzzle!($year, $day);
+
+        /// Entry points of this day, used to run it in-process.
+        #[allow(dead_code)]
+        pub const SOLUTION: advent_of_code::template::registry::Solution =
+            advent_of_code::template::registry::Solution {
+                puzzle: PUZZLE,
+                part_one: |input| part_one(&$parse(input)).map(|x| x.to_string()),
+                part_two: |input| part_two(&$parse(input)).map(|x| x.to_string()),
+                run: |input, options| {
+                    use advent_of_code::template::runner::*;
+                    let (parsed, parse) = run_parse_with($parse, input, PUZZLE, options);
+                    let mut parts = vec![];
+                    if let Some(parsed) = parsed {
+                        if options.includes_part(1) {
+                            parts.push(run_part_with(part_one, parsed, PUZZLE, 1, options));
+                        }
+                        if options.includes_part(2) {
+                            parts.push(run_part_with(part_two, parsed, PUZZLE, 2, options));
+                        }
+                    }
+                    DayResult {
+                        parse: Some(parse),
+                        parts,
+                    }
+                },
+            };
 
+        #[allow(dead_code)]
         fn main() {
             use advent_of_code::template::runner::*;
-            let input = advent_of_code::template::read_file("inputs", DAY);
-            run_part(part_one, &input, DAY, 1);
-            run_part(part_two, &input, DAY, 2);
+            let input: &'static str = advent_of_code::template::read_file("inputs", PUZZLE).leak();
+            let Some(parsed) = run_parse($parse, input, PUZZLE) else {
+                std::process::exit(1);
+            };
+            run_part(part_one, parsed, PUZZLE, 1);
+            run_part(part_two, parsed, PUZZLE, 2);
         }
     };
 }
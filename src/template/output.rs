@@ -0,0 +1,83 @@
+/// Output of the runner. Output can be buffered per thread, so that days running in parallel do not interleave.
+use std::{
+    cell::RefCell,
+    fmt::{self, Write as _},
+    io::{self, Write as _},
+};
+
+thread_local! {
+    static BUFFER: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+/// Writes to the buffer of the current thread if output is being captured, to stdout otherwise.
+pub fn write(args: fmt::Arguments) {
+    let is_buffered = BUFFER.with_borrow_mut(|buffer| match buffer {
+        Some(buffer) => {
+            let _ = buffer.write_fmt(args);
+            true
+        }
+        None => false,
+    });
+
+    if !is_buffered {
+        let mut stdout = io::stdout().lock();
+        let _ = stdout.write_fmt(args);
+        // progress is printed without a line break, make it visible right away.
+        let _ = stdout.flush();
+    }
+}
+
+/// Runs `func` while buffering the output it writes on the current thread. Returns the buffered output.
+/// Output of threads spawned by `func` is not captured.
+pub fn capture<T>(func: impl FnOnce() -> T) -> (T, String) {
+    let previous = BUFFER.replace(Some(String::new()));
+    let result = func();
+    let output = BUFFER.replace(previous).unwrap_or_default();
+    (result, output)
+}
+
+/// Like [`print!`], but can be captured with [`capture`].
+macro_rules! out {
+    ($($arg:tt)*) => {
+        $crate::template::output::write(format_args!($($arg)*))
+    };
+}
+
+/// Like [`println!`], but can be captured with [`capture`].
+macro_rules! outln {
+    () => {
+        $crate::template::output::write(format_args!("\n"))
+    };
+    ($($arg:tt)*) => {
+        $crate::template::output::write(format_args!("{}\n", format_args!($($arg)*)))
+    };
+}
+
+pub(crate) use {out, outln};
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::capture;
+
+    #[test]
+    fn captures_output_of_current_thread() {
+        let (result, output) = capture(|| {
+            out!("Part 1:");
+            outln!(" {}", 42);
+            outln!();
+            7
+        });
+        assert_eq!(result, 7);
+        assert_eq!(output, "Part 1: 42\n\n");
+    }
+
+    #[test]
+    fn nested_captures_are_separate() {
+        let (((), inner), outer) = capture(|| {
+            outln!("outer");
+            capture(|| outln!("inner"))
+        });
+        assert_eq!(inner, "inner\n");
+        assert_eq!(outer, "outer\n");
+    }
+}
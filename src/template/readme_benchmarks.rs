@@ -1,10 +1,13 @@
 /// Module that updates the readme me with timing information.
 /// The approach taken is similar to how `aoc-readme-stars` handles this.
-use std::{fs, io};
+use std::{fs, io, time::Duration};
 
-use crate::Day;
-
-static MARKER: &str = "<!--- benchmarking table --->";
+use crate::template::{
+    alloc::{format_bytes, parse_bytes, MemStats},
+    config::{config, TableConfig, TableSort},
+    get_bin_path,
+};
+use crate::{Day, PuzzleId, Year};
 
 #[derive(Debug)]
 pub enum Error {
@@ -21,23 +24,27 @@ impl From<std::io::Error> for Error {
 #[derive(Clone)]
 pub struct Timings {
     pub day: Day,
+    /// Mean time of the shared parse stage, only present if the solution declares one.
+    pub parse: Option<String>,
     pub part_1: Option<String>,
     pub part_2: Option<String>,
     pub total_nanos: f64,
+    /// Allocations of both parts combined, only present when run with `--mem`.
+    pub memory: Option<MemStats>,
 }
 
 pub struct TablePosition {
-    pos_start: usize,
-    pos_end: usize,
+    pub(crate) pos_start: usize,
+    pub(crate) pos_end: usize,
 }
 
 #[must_use]
-pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
+pub fn get_path_for_bin(puzzle: PuzzleId) -> String {
+    format!("./{}", get_bin_path(puzzle).display()).replace('\\', "/")
 }
 
-fn locate_table(readme: &str) -> Result<TablePosition, Error> {
-    let matches: Vec<_> = readme.match_indices(MARKER).collect();
+pub(crate) fn locate_table(readme: &str, marker: &str) -> Result<TablePosition, Error> {
+    let matches: Vec<_> = readme.match_indices(marker).collect();
 
     if matches.len() > 2 {
         return Err(Error::Parser(
@@ -58,74 +65,373 @@ fn locate_table(readme: &str) -> Result<TablePosition, Error> {
     Ok(TablePosition { pos_start, pos_end })
 }
 
-fn construct_table(prefix: &str, timings: Vec<Timings>, total_millis: f64) -> String {
-    let header = format!("{prefix} Benchmarks");
+fn format_nanos(nanos: f64) -> String {
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    let duration = Duration::from_nanos(nanos.round() as u64);
+    format!("{duration:.1?}")
+}
+
+/// Change of a day's combined time versus `previous`, `-` if there is nothing to compare.
+fn format_delta(nanos: f64, previous: Option<f64>) -> String {
+    match previous {
+        Some(previous) if previous > 0_f64 && nanos > 0_f64 && nanos != previous => {
+            format!("{:+.1}%", (nanos - previous) / previous * 100_f64)
+        }
+        _ => "-".into(),
+    }
+}
+
+fn construct_table(
+    prefix: &str,
+    marker: &str,
+    year: Year,
+    mut timings: Vec<Timings>,
+    previous: &[Timings],
+    total_millis: f64,
+    layout: &TableConfig,
+) -> String {
+    let header = format!("{prefix} Benchmarks {year}");
+    let has_parse = timings.iter().any(|timing| timing.parse.is_some());
+    let has_memory = timings.iter().any(|timing| timing.memory.is_some());
+
+    if layout.sort == TableSort::Time {
+        timings.sort_by(|a, b| {
+            b.total_nanos
+                .total_cmp(&a.total_nanos)
+                .then(a.day.cmp(&b.day))
+        });
+    }
+
+    let mut by_cost: Vec<&Timings> = timings.iter().filter(|x| x.total_nanos > 0_f64).collect();
+    by_cost.sort_by(|a, b| b.total_nanos.total_cmp(&a.total_nanos));
+    let slowest: Vec<Day> = by_cost
+        .iter()
+        .take(layout.highlight)
+        .map(|timing| timing.day)
+        .collect();
+
+    let mut lines: Vec<String> = vec![marker.into(), header, String::new()];
+
+    let mut columns = String::from("| Day |");
+    let mut alignments = String::from("| :---: |");
+
+    if has_parse {
+        columns.push_str(" Parse |");
+        alignments.push_str(" :---: |");
+    }
+
+    columns.push_str(" Part 1 | Part 2 |");
+    alignments.push_str(" :---: | :---:  |");
+
+    for (is_enabled, column) in [
+        (layout.total, "Total"),
+        (layout.share, "Share"),
+        (layout.delta, "Change"),
+    ] {
+        if is_enabled {
+            columns.push_str(&format!(" {column} |"));
+            alignments.push_str(" :---: |");
+        }
+    }
+
+    if has_memory {
+        columns.push_str(" Allocations | Peak memory |");
+        alignments.push_str(" :---: | :---: |");
+    }
 
-    let mut lines: Vec<String> = vec![
-        MARKER.into(),
-        header,
-        String::new(),
-        "| Day | Part 1 | Part 2 |".into(),
-        "| :---: | :---: | :---:  |".into(),
-    ];
+    lines.push(columns);
+    lines.push(alignments);
 
     for timing in timings {
-        let path = get_path_for_bin(timing.day);
-        lines.push(format!(
-            "| [Day {}]({}) | `{}` | `{}` |",
-            timing.day.into_inner(),
-            path,
+        let path = get_path_for_bin(PuzzleId::__new_unchecked(year, timing.day));
+        let link = format!("[Day {}]({})", timing.day.into_inner(), path);
+        let mut line = if slowest.contains(&timing.day) {
+            format!("| **{link}** |")
+        } else {
+            format!("| {link} |")
+        };
+
+        if has_parse {
+            line.push_str(&format!(
+                " `{}` |",
+                timing.parse.unwrap_or_else(|| "-".into())
+            ));
+        }
+
+        line.push_str(&format!(
+            " `{}` | `{}` |",
             timing.part_1.unwrap_or_else(|| "-".into()),
             timing.part_2.unwrap_or_else(|| "-".into())
         ));
+
+        let has_time = timing.total_nanos > 0_f64;
+
+        if layout.total {
+            let total = if has_time {
+                format_nanos(timing.total_nanos)
+            } else {
+                "-".into()
+            };
+            line.push_str(&format!(" `{total}` |"));
+        }
+
+        if layout.share {
+            let share = if has_time && total_millis > 0_f64 {
+                format!("{:.1}%", timing.total_nanos / (total_millis * 10_000_f64))
+            } else {
+                "-".into()
+            };
+            line.push_str(&format!(" `{share}` |"));
+        }
+
+        if layout.delta {
+            let previous = previous
+                .iter()
+                .find(|x| x.day == timing.day)
+                .map(|x| x.total_nanos);
+            line.push_str(&format!(
+                " `{}` |",
+                format_delta(timing.total_nanos, previous)
+            ));
+        }
+
+        if has_memory {
+            match timing.memory {
+                Some(memory) => line.push_str(&format!(
+                    " `{}` | `{}` |",
+                    memory.allocations,
+                    format_bytes(memory.peak_bytes)
+                )),
+                None => line.push_str(" `-` | `-` |"),
+            }
+        }
+
+        lines.push(line);
     }
 
     lines.push(String::new());
     lines.push(format!("**Total: {total_millis:.2}ms**"));
-    lines.push(MARKER.into());
+    lines.push(marker.into());
 
     lines.join("\n")
 }
 
-fn update_content(s: &mut String, timings: Vec<Timings>, total_millis: f64) -> Result<(), Error> {
-    let positions = locate_table(s)?;
-    let table = construct_table("##", timings, total_millis);
+/// Reads a duration formatted with `{:.1?}`, e.g. `74.1ms`, as nanoseconds.
+fn parse_nanos(s: &str) -> Option<f64> {
+    const UNITS: [(&str, f64); 4] = [("ns", 1.0), ("µs", 1e3), ("ms", 1e6), ("s", 1e9)];
+
+    UNITS.iter().find_map(|(unit, factor)| {
+        let value: f64 = s.strip_suffix(unit)?.parse().ok()?;
+        Some(value * factor)
+    })
+}
+
+fn get_total_nanos(timing: &Timings) -> f64 {
+    [&timing.parse, &timing.part_1, &timing.part_2]
+        .into_iter()
+        .filter_map(|x| parse_nanos(x.as_deref()?))
+        .sum()
+}
+
+/// Reads the rows of a table written by [`construct_table`]. Totals are derived from the formatted timings.
+/// Columns that are computed from the timings, like the share of the total, are not read.
+fn parse_table(table: &str) -> Vec<Timings> {
+    let cells = |line: &str| -> Vec<String> {
+        line.trim()
+            .trim_matches('|')
+            .split('|')
+            .map(|cell| cell.trim().trim_matches(['`', '*']).to_string())
+            .collect()
+    };
+
+    let Some(columns) = table
+        .lines()
+        .find(|line| line.starts_with("| Day |"))
+        .map(cells)
+    else {
+        return vec![];
+    };
+
+    table
+        .lines()
+        .filter(|line| line.starts_with("| [Day ") || line.starts_with("| **[Day "))
+        .filter_map(|line| {
+            let row = cells(line);
+            let day = row[0]
+                .strip_prefix("[Day ")?
+                .split(']')
+                .next()?
+                .parse()
+                .ok()?;
+            let cell = |name: &str| {
+                let i = columns.iter().position(|column| column == name)?;
+                row.get(i).filter(|x| *x != "-").cloned()
+            };
+
+            let memory = cell("Allocations").map(|allocations| MemStats {
+                allocations: allocations.parse().unwrap_or_default(),
+                bytes: 0,
+                peak_bytes: cell("Peak memory")
+                    .and_then(|x| parse_bytes(&x))
+                    .unwrap_or_default(),
+            });
+
+            let mut timing = Timings {
+                day,
+                parse: cell("Parse"),
+                part_1: cell("Part 1"),
+                part_2: cell("Part 2"),
+                total_nanos: 0_f64,
+                memory,
+            };
+            timing.total_nanos = get_total_nanos(&timing);
+            Some(timing)
+        })
+        .collect()
+}
+
+/// Replaces the rows of re-timed days in `existing`. With `part`, the other part's column of these rows is kept.
+/// Memory columns are figures of both parts, so with `part` they are left as they are.
+fn merge_timings(existing: Vec<Timings>, timings: Vec<Timings>, part: Option<u8>) -> Vec<Timings> {
+    let mut merged = existing;
+
+    for mut timing in timings {
+        let Some(row) = merged.iter_mut().find(|row| row.day == timing.day) else {
+            if part.is_some() {
+                timing.memory = None;
+            }
+            merged.push(timing);
+            continue;
+        };
+
+        let Some(part) = part else {
+            *row = timing;
+            continue;
+        };
+
+        // the parse stage runs for either part.
+        row.parse = timing.parse;
+        if part == 1 {
+            row.part_1 = timing.part_1;
+        } else {
+            row.part_2 = timing.part_2;
+        }
+        row.total_nanos = get_total_nanos(row);
+    }
+
+    merged.sort_by_key(|row| row.day);
+    merged
+}
+
+fn update_content(
+    s: &mut String,
+    marker: &str,
+    year: Year,
+    timings: Vec<Timings>,
+    total_millis: f64,
+    layout: &TableConfig,
+) -> Result<(), Error> {
+    let positions = locate_table(s, marker)?;
+    let previous = parse_table(&s[positions.pos_start..positions.pos_end]);
+    let table = construct_table("##", marker, year, timings, &previous, total_millis, layout);
     s.replace_range(positions.pos_start..positions.pos_end, &table);
     Ok(())
 }
 
-pub fn update(timings: Vec<Timings>, total_millis: f64) -> Result<(), Error> {
-    let path = "README.md";
+/// Updates the table of a year in the readme configured in `aoc.toml`.
+pub fn update(year: Year, timings: Vec<Timings>, total_millis: f64) -> Result<(), Error> {
+    let readme_config = &config().readme;
+    let path = &readme_config.path;
+    let mut readme = String::from_utf8_lossy(&fs::read(path)?).to_string();
+    update_content(
+        &mut readme,
+        &readme_config.marker(year),
+        year,
+        timings,
+        total_millis,
+        &readme_config.table,
+    )?;
+    fs::write(path, &readme)?;
+    Ok(())
+}
+
+fn update_partial_content(
+    s: &mut String,
+    marker: &str,
+    year: Year,
+    timings: Vec<Timings>,
+    part: Option<u8>,
+    layout: &TableConfig,
+) -> Result<(), Error> {
+    let positions = locate_table(s, marker)?;
+    let existing = parse_table(&s[positions.pos_start..positions.pos_end]);
+    let merged = merge_timings(existing, timings, part);
+    let total_millis = merged.iter().map(|x| x.total_nanos).sum::<f64>() / 1_000_000_f64;
+    update_content(s, marker, year, merged, total_millis, layout)
+}
+
+/// Updates the rows of the re-timed days in the table of a year, every other row stays as it was.
+/// With `part`, only that part's column of these rows changes. The total is derived from the merged rows.
+pub fn update_partial(year: Year, timings: Vec<Timings>, part: Option<u8>) -> Result<(), Error> {
+    let readme_config = &config().readme;
+    let path = &readme_config.path;
     let mut readme = String::from_utf8_lossy(&fs::read(path)?).to_string();
-    update_content(&mut readme, timings, total_millis)?;
+    update_partial_content(
+        &mut readme,
+        &readme_config.marker(year),
+        year,
+        timings,
+        part,
+        &readme_config.table,
+    )?;
     fs::write(path, &readme)?;
     Ok(())
 }
 
 #[cfg(feature = "test_lib")]
 mod tests {
-    use super::{update_content, Timings, MARKER};
-    use crate::day;
+    use super::{get_total_nanos, parse_table, update_content, update_partial_content, Timings};
+    use crate::template::{
+        alloc::MemStats,
+        config::{ReadmeConfig, TableConfig, TableSort},
+    };
+    use crate::{day, year, Day, Year};
+
+    const YEAR: Year = year!(2023);
+
+    fn get_marker(year: Year) -> String {
+        ReadmeConfig::default().marker(year)
+    }
+
+    fn layout() -> TableConfig {
+        TableConfig::default()
+    }
 
     fn get_mock_timings() -> Vec<Timings> {
         vec![
             Timings {
                 day: day!(1),
+                parse: None,
                 part_1: Some("10ms".into()),
                 part_2: Some("20ms".into()),
                 total_nanos: 3e+10,
+                memory: None,
             },
             Timings {
                 day: day!(2),
+                parse: None,
                 part_1: Some("30ms".into()),
                 part_2: Some("40ms".into()),
                 total_nanos: 7e+10,
+                memory: None,
             },
             Timings {
                 day: day!(4),
+                parse: None,
                 part_1: Some("40ms".into()),
                 part_2: Some("50ms".into()),
                 total_nanos: 9e+10,
+                memory: None,
             },
         ]
     }
@@ -133,54 +439,238 @@ mod tests {
     #[test]
     #[should_panic]
     fn errors_if_marker_not_present() {
+        let marker = get_marker(YEAR);
         let mut s = "# readme".to_string();
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, &marker, YEAR, get_mock_timings(), 190.0, &layout()).unwrap();
     }
 
     #[test]
     #[should_panic]
     fn errors_if_too_many_markers_present() {
-        let mut s = format!("{} {} {}", MARKER, MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        let marker = get_marker(YEAR);
+        let mut s = format!("{} {} {}", marker, marker, marker);
+        update_content(&mut s, &marker, YEAR, get_mock_timings(), 190.0, &layout()).unwrap();
     }
 
     #[test]
     fn updates_empty_benchmarks() {
-        let mut s = format!("foo\nbar\n{}{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
-        assert_eq!(s.contains("## Benchmarks"), true);
+        let marker = get_marker(YEAR);
+        let mut s = format!("foo\nbar\n{}{}\nbaz", marker, marker);
+        update_content(&mut s, &marker, YEAR, get_mock_timings(), 190.0, &layout()).unwrap();
+        assert_eq!(s.contains("## Benchmarks 2023"), true);
     }
 
     #[test]
     fn updates_existing_benchmarks() {
-        let mut s = format!("foo\nbar\n{}{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
-        assert_eq!(s.matches(MARKER).collect::<Vec<&str>>().len(), 2);
+        let marker = get_marker(YEAR);
+        let mut s = format!("foo\nbar\n{}{}\nbaz", marker, marker);
+        update_content(&mut s, &marker, YEAR, get_mock_timings(), 190.0, &layout()).unwrap();
+        update_content(&mut s, &marker, YEAR, get_mock_timings(), 190.0, &layout()).unwrap();
+        assert_eq!(s.matches(&marker).collect::<Vec<&str>>().len(), 2);
         assert_eq!(s.matches("## Benchmarks").collect::<Vec<&str>>().len(), 1);
     }
 
+    #[test]
+    fn keeps_tables_of_other_years() {
+        let other = get_marker(year!(2022));
+        let marker = get_marker(YEAR);
+        let mut s = format!("{other}\nold\n{other}\n{marker}{marker}");
+        update_content(&mut s, &marker, YEAR, get_mock_timings(), 190.0, &layout()).unwrap();
+        assert!(s.starts_with(&format!("{other}\nold\n{other}\n")));
+        assert_eq!(
+            s.matches("## Benchmarks 2023").collect::<Vec<&str>>().len(),
+            1
+        );
+    }
+
     #[test]
     fn format_benchmarks() {
-        let mut s = format!("foo\nbar\n{}\n{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        let marker = get_marker(YEAR);
+        let mut s = format!("foo\nbar\n{}\n{}\nbaz", marker, marker);
+        update_content(&mut s, &marker, YEAR, get_mock_timings(), 190.0, &layout()).unwrap();
         let expected = [
             "foo",
             "bar",
-            "<!--- benchmarking table --->",
-            "## Benchmarks",
+            "<!--- benchmarking table 2023 --->",
+            "## Benchmarks 2023",
             "",
             "| Day | Part 1 | Part 2 |",
             "| :---: | :---: | :---:  |",
-            "| [Day 1](./src/bin/01.rs) | `10ms` | `20ms` |",
-            "| [Day 2](./src/bin/02.rs) | `30ms` | `40ms` |",
-            "| [Day 4](./src/bin/04.rs) | `40ms` | `50ms` |",
+            "| [Day 1](./src/bin/2023/01.rs) | `10ms` | `20ms` |",
+            "| [Day 2](./src/bin/2023/02.rs) | `30ms` | `40ms` |",
+            "| [Day 4](./src/bin/2023/04.rs) | `40ms` | `50ms` |",
             "",
             "**Total: 190.00ms**",
-            "<!--- benchmarking table --->",
+            "<!--- benchmarking table 2023 --->",
             "baz",
         ]
         .join("\n");
         assert_eq!(s, expected);
     }
+
+    #[test]
+    fn format_benchmarks_with_memory() {
+        let marker = get_marker(YEAR);
+        let mut s = format!("{}{}", marker, marker);
+        let mut timings = get_mock_timings();
+        timings[0].memory = Some(MemStats {
+            allocations: 12,
+            bytes: 4096,
+            peak_bytes: 2048,
+        });
+        update_content(&mut s, &marker, YEAR, timings, 190.0, &layout()).unwrap();
+        assert!(s.contains("| Day | Part 1 | Part 2 | Allocations | Peak memory |"));
+        assert!(
+            s.contains("| [Day 1](./src/bin/2023/01.rs) | `10ms` | `20ms` | `12` | `2.0 KiB` |")
+        );
+        assert!(s.contains("| [Day 2](./src/bin/2023/02.rs) | `30ms` | `40ms` | `-` | `-` |"));
+    }
+
+    #[test]
+    fn format_benchmarks_with_parse() {
+        let marker = get_marker(YEAR);
+        let mut s = format!("{}{}", marker, marker);
+        let mut timings = get_mock_timings();
+        timings[1].parse = Some("5ms".into());
+        update_content(&mut s, &marker, YEAR, timings, 190.0, &layout()).unwrap();
+        assert!(s.contains("| Day | Parse | Part 1 | Part 2 |"));
+        assert!(s.contains("| [Day 1](./src/bin/2023/01.rs) | `-` | `10ms` | `20ms` |"));
+        assert!(s.contains("| [Day 2](./src/bin/2023/02.rs) | `5ms` | `30ms` | `40ms` |"));
+    }
+
+    fn get_timing(day: Day, part_1: Option<&str>, part_2: Option<&str>) -> Timings {
+        let mut timing = Timings {
+            day,
+            parse: None,
+            part_1: part_1.map(Into::into),
+            part_2: part_2.map(Into::into),
+            total_nanos: 0_f64,
+            memory: None,
+        };
+        timing.total_nanos = get_total_nanos(&timing);
+        timing
+    }
+
+    #[test]
+    fn reads_written_table() {
+        let marker = get_marker(YEAR);
+        let mut s = format!("{}{}", marker, marker);
+        let mut timings = get_mock_timings();
+        timings[1].parse = Some("5.0µs".into());
+        timings[2].memory = Some(MemStats {
+            allocations: 12,
+            bytes: 4096,
+            peak_bytes: 2048,
+        });
+        update_content(&mut s, &marker, YEAR, timings, 190.0, &layout()).unwrap();
+
+        let rows = parse_table(&s);
+        assert_eq!(rows.len(), 3);
+        assert_eq!(rows[1].day, day!(2));
+        assert_eq!(rows[1].parse.as_deref(), Some("5.0µs"));
+        assert_eq!(rows[1].part_2.as_deref(), Some("40ms"));
+        assert_eq!(rows[0].parse, None);
+        assert_eq!(rows[0].memory, None);
+        assert_eq!(rows[2].memory.unwrap().peak_bytes, 2048);
+        assert!((rows[1].total_nanos - 70_005_000_f64).abs() < 1e-3);
+    }
+
+    #[test]
+    fn updates_selected_rows_only() {
+        let marker = get_marker(YEAR);
+        let mut s = format!("{}{}", marker, marker);
+        update_content(&mut s, &marker, YEAR, get_mock_timings(), 190.0, &layout()).unwrap();
+
+        let timings = vec![
+            get_timing(day!(2), Some("1ms"), Some("2ms")),
+            get_timing(day!(3), Some("3ms"), None),
+        ];
+        update_partial_content(&mut s, &marker, YEAR, timings, None, &layout()).unwrap();
+
+        assert!(s.contains("| [Day 1](./src/bin/2023/01.rs) | `10ms` | `20ms` |"));
+        assert!(s.contains("| [Day 2](./src/bin/2023/02.rs) | `1ms` | `2ms` |"));
+        assert!(s.contains("| [Day 3](./src/bin/2023/03.rs) | `3ms` | `-` |"));
+        assert!(s.contains("| [Day 4](./src/bin/2023/04.rs) | `40ms` | `50ms` |"));
+        assert!(s.contains("**Total: 126.00ms**"));
+    }
+
+    #[test]
+    fn updates_selected_part_only() {
+        let marker = get_marker(YEAR);
+        let mut s = format!("{}{}", marker, marker);
+        update_content(&mut s, &marker, YEAR, get_mock_timings(), 190.0, &layout()).unwrap();
+
+        let timings = vec![get_timing(day!(4), Some("5ms"), None)];
+        update_partial_content(&mut s, &marker, YEAR, timings, Some(1), &layout()).unwrap();
+
+        assert!(s.contains("| [Day 2](./src/bin/2023/02.rs) | `30ms` | `40ms` |"));
+        assert!(s.contains("| [Day 4](./src/bin/2023/04.rs) | `5ms` | `50ms` |"));
+        assert!(s.contains("**Total: 155.00ms**"));
+    }
+
+    #[test]
+    fn keeps_memory_when_updating_a_single_part() {
+        let memory = |allocations| {
+            Some(MemStats {
+                allocations,
+                bytes: 4096,
+                peak_bytes: 2048,
+            })
+        };
+
+        let marker = get_marker(YEAR);
+        let mut s = format!("{}{}", marker, marker);
+        let mut timings = get_mock_timings();
+        timings[0].memory = memory(12);
+        update_content(&mut s, &marker, YEAR, timings, 190.0, &layout()).unwrap();
+
+        let mut timing = get_timing(day!(1), Some("5ms"), None);
+        timing.memory = memory(3);
+        let mut new_timing = get_timing(day!(5), Some("5ms"), None);
+        new_timing.memory = memory(3);
+        let timings = vec![timing, new_timing];
+        update_partial_content(&mut s, &marker, YEAR, timings, Some(1), &layout()).unwrap();
+
+        let rows = parse_table(&s);
+        assert_eq!(rows[0].part_1.as_deref(), Some("5ms"));
+        assert_eq!(rows[0].memory.unwrap().allocations, 12);
+        assert_eq!(rows[3].day, day!(5));
+        assert_eq!(rows[3].memory, None);
+    }
+
+    #[test]
+    fn format_benchmarks_with_layout() {
+        let marker = get_marker(YEAR);
+        let mut s = format!("{}{}", marker, marker);
+        let layout = TableConfig {
+            total: true,
+            share: true,
+            delta: true,
+            sort: TableSort::Time,
+            highlight: 1,
+        };
+
+        let timings = vec![
+            get_timing(day!(1), Some("10ms"), Some("20ms")),
+            get_timing(day!(2), Some("30ms"), Some("40ms")),
+        ];
+        update_content(&mut s, &marker, YEAR, timings, 100.0, &layout).unwrap();
+
+        let timings = vec![
+            get_timing(day!(1), Some("10ms"), Some("10ms")),
+            get_timing(day!(2), Some("30ms"), Some("40ms")),
+            get_timing(day!(3), None, None),
+        ];
+        update_content(&mut s, &marker, YEAR, timings, 90.0, &layout).unwrap();
+
+        let expected = [
+            "| Day | Part 1 | Part 2 | Total | Share | Change |",
+            "| :---: | :---: | :---:  | :---: | :---: | :---: |",
+            "| **[Day 2](./src/bin/2023/02.rs)** | `30ms` | `40ms` | `70.0ms` | `77.8%` | `-` |",
+            "| [Day 1](./src/bin/2023/01.rs) | `10ms` | `10ms` | `20.0ms` | `22.2%` | `-33.3%` |",
+            "| [Day 3](./src/bin/2023/03.rs) | `-` | `-` | `-` | `-` | `-` |",
+        ]
+        .join("\n");
+        assert!(s.contains(&expected), "{s}");
+    }
 }
@@ -0,0 +1,252 @@
+/// Module that updates the readme with the progress of a year: which days are scaffolded, pass their example tests and are solved.
+/// Progress is read from the solution files and the local answers and submission records, no requests are sent to the website.
+use std::fs;
+
+use crate::template::{
+    answers::Answers,
+    config::config,
+    readme_benchmarks::{get_path_for_bin, locate_table, Error},
+    submissions::{Submission, Verdict},
+};
+use crate::{Day, PuzzleId, Year};
+
+/// Whether a part of a day is solved, according to the local records.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PartStatus {
+    Unsolved,
+    /// Only wrong answers were submitted so far, holds their number.
+    Attempted(usize),
+    Solved,
+}
+
+impl PartStatus {
+    /// A part is solved if an answer is stored for it or the server accepted one.
+    /// [`Verdict::AlreadySolved`] does not count: the server replies the same for a part that is not unlocked yet.
+    #[must_use]
+    pub fn from_records(answers: &Answers, log: &[Submission], part: u8) -> Self {
+        let submissions = log.iter().filter(|submission| submission.part == part);
+
+        if answers.get(part).is_some()
+            || submissions
+                .clone()
+                .any(|submission| submission.verdict == Verdict::Correct)
+        {
+            return PartStatus::Solved;
+        }
+
+        match submissions
+            .filter(|submission| submission.verdict.is_wrong())
+            .count()
+        {
+            0 => PartStatus::Unsolved,
+            wrong => PartStatus::Attempted(wrong),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DayProgress {
+    pub day: Day,
+    /// Whether the solution file of the day exists.
+    pub is_scaffolded: bool,
+    /// Whether the example tests of the day pass, [`None`] if they did not run.
+    pub example: Option<bool>,
+    pub part_1: PartStatus,
+    pub part_2: PartStatus,
+}
+
+impl DayProgress {
+    #[must_use]
+    pub fn stars(&self) -> usize {
+        [self.part_1, self.part_2]
+            .iter()
+            .filter(|status| **status == PartStatus::Solved)
+            .count()
+    }
+}
+
+fn format_part(status: PartStatus) -> String {
+    match status {
+        PartStatus::Unsolved => "-".into(),
+        PartStatus::Attempted(wrong) => format!("✘ ({wrong} wrong)"),
+        PartStatus::Solved => "⭐".into(),
+    }
+}
+
+fn construct_table(prefix: &str, marker: &str, year: Year, progress: &[DayProgress]) -> String {
+    let stars: usize = progress.iter().map(DayProgress::stars).sum();
+    let max_stars = usize::from(year.day_count()) * 2;
+
+    let mut lines: Vec<String> = vec![
+        marker.into(),
+        format!("{prefix} Progress {year}"),
+        String::new(),
+        "| Day | Scaffolded | Example | Part 1 | Part 2 |".into(),
+        "| :---: | :---: | :---: | :---: | :---: |".into(),
+    ];
+
+    for day in progress {
+        let label = if day.is_scaffolded {
+            let path = get_path_for_bin(PuzzleId::__new_unchecked(year, day.day));
+            format!("[Day {}]({path})", day.day.into_inner())
+        } else {
+            format!("Day {}", day.day.into_inner())
+        };
+
+        let example = match day.example {
+            Some(true) => "✔",
+            Some(false) => "✘",
+            None => "-",
+        };
+
+        lines.push(format!(
+            "| {label} | {} | {example} | {} | {} |",
+            if day.is_scaffolded { "✔" } else { "-" },
+            format_part(day.part_1),
+            format_part(day.part_2)
+        ));
+    }
+
+    lines.push(String::new());
+    lines.push(format!("**Stars: {stars}/{max_stars} ⭐**"));
+    lines.push(marker.into());
+
+    lines.join("\n")
+}
+
+fn update_content(
+    s: &mut String,
+    marker: &str,
+    year: Year,
+    progress: &[DayProgress],
+) -> Result<(), Error> {
+    let positions = locate_table(s, marker)?;
+    let table = construct_table("##", marker, year, progress);
+    s.replace_range(positions.pos_start..positions.pos_end, &table);
+    Ok(())
+}
+
+/// Updates the progress table of a year in the readme configured in `aoc.toml`.
+pub fn update(year: Year, progress: &[DayProgress]) -> Result<(), Error> {
+    let readme_config = &config().readme;
+    let path = &readme_config.path;
+    let mut readme = String::from_utf8_lossy(&fs::read(path)?).to_string();
+    update_content(
+        &mut readme,
+        &readme_config.progress_marker(year),
+        year,
+        progress,
+    )?;
+    fs::write(path, &readme)?;
+    Ok(())
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{update_content, DayProgress, PartStatus};
+    use crate::template::{
+        answers::Answers,
+        config::ReadmeConfig,
+        submissions::{Submission, Verdict},
+    };
+    use crate::{day, year, Year};
+
+    const YEAR: Year = year!(2023);
+
+    fn submission(part: u8, verdict: Verdict) -> Submission {
+        Submission {
+            part,
+            answer: "42".into(),
+            verdict,
+            timestamp: 0,
+            wait_until: None,
+        }
+    }
+
+    #[test]
+    fn reads_part_status_from_records() {
+        let mut answers = Answers::default();
+        assert_eq!(
+            PartStatus::from_records(&answers, &[], 1),
+            PartStatus::Unsolved
+        );
+
+        let log = [
+            submission(1, Verdict::TooHigh),
+            submission(1, Verdict::RateLimited),
+            submission(1, Verdict::TooLow),
+            submission(2, Verdict::AlreadySolved),
+        ];
+        assert_eq!(
+            PartStatus::from_records(&answers, &log, 1),
+            PartStatus::Attempted(2)
+        );
+        assert_eq!(
+            PartStatus::from_records(&answers, &log, 2),
+            PartStatus::Unsolved
+        );
+
+        let log = [
+            submission(2, Verdict::TooLow),
+            submission(2, Verdict::Correct),
+        ];
+        assert_eq!(
+            PartStatus::from_records(&answers, &log, 2),
+            PartStatus::Solved
+        );
+
+        answers.set(1, "42");
+        assert_eq!(
+            PartStatus::from_records(&answers, &log, 1),
+            PartStatus::Solved
+        );
+    }
+
+    #[test]
+    fn format_progress() {
+        let marker = ReadmeConfig::default().progress_marker(YEAR);
+        let mut s = format!("foo\n{marker}\n{marker}\nbar");
+        let progress = [
+            DayProgress {
+                day: day!(1),
+                is_scaffolded: true,
+                example: Some(true),
+                part_1: PartStatus::Solved,
+                part_2: PartStatus::Solved,
+            },
+            DayProgress {
+                day: day!(2),
+                is_scaffolded: true,
+                example: Some(false),
+                part_1: PartStatus::Solved,
+                part_2: PartStatus::Attempted(3),
+            },
+            DayProgress {
+                day: day!(3),
+                is_scaffolded: false,
+                example: None,
+                part_1: PartStatus::Unsolved,
+                part_2: PartStatus::Unsolved,
+            },
+        ];
+        update_content(&mut s, &marker, YEAR, &progress).unwrap();
+
+        let expected = [
+            "foo",
+            "<!--- progress table 2023 --->",
+            "## Progress 2023",
+            "",
+            "| Day | Scaffolded | Example | Part 1 | Part 2 |",
+            "| :---: | :---: | :---: | :---: | :---: |",
+            "| [Day 1](./src/bin/2023/01.rs) | ✔ | ✔ | ⭐ | ⭐ |",
+            "| [Day 2](./src/bin/2023/02.rs) | ✔ | ✘ | ⭐ | ✘ (3 wrong) |",
+            "| Day 3 | - | - | - | - |",
+            "",
+            "**Stars: 3/50 ⭐**",
+            "<!--- progress table 2023 --->",
+            "bar",
+        ]
+        .join("\n");
+        assert_eq!(s, expected);
+    }
+}
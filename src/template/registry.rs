@@ -0,0 +1,56 @@
+/// In-process registry of every solution in `src/bin`.
+/// The list is generated by `build.rs` and compiled into the `aoc-runner` binary only, each entry is defined by the `solution!` macro of its day.
+/// The library and the `advent_of_code` binary do not include solutions, so a day that does not compile does not break the other commands.
+/// Without registered solutions, `all` and `verify` run in `aoc-runner`, or run the binary of each day if it does not build, see [`crate::template::child`].
+use std::sync::OnceLock;
+
+use crate::template::runner::{DayResult, RunOptions};
+use crate::{Day, PuzzleId, Year};
+
+/// Type-erased entry points of a day's solution.
+#[derive(Clone, Copy)]
+pub struct Solution {
+    pub puzzle: PuzzleId,
+    /// Solves part one from the raw input, including the parse stage if there is one.
+    pub part_one: fn(&str) -> Option<String>,
+    /// Solves part two from the raw input, including the parse stage if there is one.
+    pub part_two: fn(&str) -> Option<String>,
+    /// Runs and reports the parse stage and both parts, timing each of them separately.
+    /// The input must outlive parts that exceed the time limit, as they keep running in the background.
+    pub run: fn(&'static str, RunOptions) -> DayResult,
+}
+
+static SOLUTIONS: OnceLock<&'static [Solution]> = OnceLock::new();
+
+/// Registers the solutions compiled into the current binary. Only the first call has an effect.
+pub fn register(solutions: &'static [Solution]) {
+    let _ = SOLUTIONS.set(solutions);
+}
+
+/// Whether the current binary has solutions compiled in, i.e. is `aoc-runner`.
+#[must_use]
+pub fn is_registered() -> bool {
+    SOLUTIONS.get().is_some()
+}
+
+/// All registered solutions, ordered by year and day.
+#[must_use]
+pub fn solutions() -> &'static [Solution] {
+    SOLUTIONS.get().copied().unwrap_or_default()
+}
+
+/// Returns the solution for a given puzzle if it is registered.
+#[must_use]
+pub fn get_solution(puzzle: PuzzleId) -> Option<&'static Solution> {
+    solutions()
+        .iter()
+        .find(|solution| solution.puzzle == puzzle)
+}
+
+/// An iterator over every day of a year that has a registered solution.
+pub fn implemented_days(year: Year) -> impl Iterator<Item = Day> {
+    solutions()
+        .iter()
+        .filter(move |solution| solution.puzzle.year == year)
+        .map(|solution| solution.puzzle.day)
+}
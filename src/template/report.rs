@@ -0,0 +1,464 @@
+/// Module that writes benchmark reports of a timed run to a file, in addition to the table in the readme.
+/// Every format is a [`ReportWriter`], see [`ReportFormat::writer`].
+use std::{
+    fmt::{Display, Write as _},
+    fs, io,
+    path::{Path, PathBuf},
+    str::FromStr,
+    time::Duration,
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::template::{
+    alloc::{format_bytes, MemStats},
+    runner::{ParseResult, PartResult},
+};
+use crate::{Day, Year};
+
+#[derive(Debug)]
+pub enum Error {
+    Parser(String),
+    IO(io::Error),
+}
+
+impl From<std::io::Error> for Error {
+    fn from(e: std::io::Error) -> Self {
+        Error::IO(e)
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Parser(e) => write!(f, "could not render report: {e}"),
+            Error::IO(e) => write!(f, "could not write report: {e}"),
+        }
+    }
+}
+
+/// Benchmarks of a timed run of a year.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Report {
+    pub year: Year,
+    pub days: Vec<DayReport>,
+}
+
+/// Mean execution times of a day in nanoseconds, [`None`] for stages that did not run or failed.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DayReport {
+    pub day: Day,
+    pub parse: Option<u64>,
+    pub part_1: Option<u64>,
+    pub part_2: Option<u64>,
+    /// Allocations of the parse stage and both parts combined, only present when run with `--mem`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub memory: Option<MemStats>,
+}
+
+impl DayReport {
+    #[must_use]
+    pub fn from_results(day: Day, parse: Option<&ParseResult>, parts: &[PartResult]) -> Self {
+        #[allow(clippy::cast_possible_truncation)]
+        let nanos = |duration: Duration| duration.as_nanos() as u64;
+
+        let part = |part: u8| {
+            parts
+                .iter()
+                .find(|result| result.part == part && result.success)
+                .map(|result| nanos(result.stats.mean))
+        };
+
+        let parse = parse.filter(|parse| parse.error.is_none());
+
+        let memory = parse
+            .and_then(|parse| parse.mem)
+            .into_iter()
+            .chain(parts.iter().filter_map(|result| result.mem))
+            .reduce(|a, b| MemStats {
+                allocations: a.allocations + b.allocations,
+                bytes: a.bytes + b.bytes,
+                peak_bytes: a.peak_bytes.max(b.peak_bytes),
+            });
+
+        Self {
+            day,
+            parse: parse.map(|parse| nanos(parse.stats.mean)),
+            part_1: part(1),
+            part_2: part(2),
+            memory,
+        }
+    }
+
+    /// Combined time of the parse stage and both parts.
+    #[must_use]
+    pub fn total(&self) -> u64 {
+        [self.parse, self.part_1, self.part_2]
+            .into_iter()
+            .flatten()
+            .sum()
+    }
+}
+
+impl Report {
+    #[must_use]
+    pub fn total(&self) -> u64 {
+        self.days.iter().map(DayReport::total).sum()
+    }
+}
+
+/// Renders a [`Report`] in one file format.
+pub trait ReportWriter {
+    fn render(&self, report: &Report) -> Result<String, Error>;
+}
+
+/// File formats of `--report`.
+///  1. `csv` writes one row per day, for spreadsheets.
+///  2. `json` writes the whole report as one JSON document.
+///  3. `html` writes a self-contained page with a table and a bar chart.
+///  4. `svg` writes a bar chart of the time of each day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReportFormat {
+    Csv,
+    Json,
+    Html,
+    Svg,
+}
+
+impl ReportFormat {
+    #[must_use]
+    pub fn writer(self) -> Box<dyn ReportWriter> {
+        match self {
+            ReportFormat::Csv => Box::new(CsvWriter),
+            ReportFormat::Json => Box::new(JsonWriter),
+            ReportFormat::Html => Box::new(HtmlWriter),
+            ReportFormat::Svg => Box::new(SvgWriter),
+        }
+    }
+
+    /// Path of a report if `--out` is not passed, e.g. `benchmarks-2023.csv`.
+    #[must_use]
+    pub fn default_path(self, year: Year) -> PathBuf {
+        PathBuf::from(format!("benchmarks-{year}.{self}"))
+    }
+}
+
+impl Display for ReportFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ReportFormat::Csv => f.write_str("csv"),
+            ReportFormat::Json => f.write_str("json"),
+            ReportFormat::Html => f.write_str("html"),
+            ReportFormat::Svg => f.write_str("svg"),
+        }
+    }
+}
+
+impl FromStr for ReportFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "csv" => Ok(ReportFormat::Csv),
+            "json" => Ok(ReportFormat::Json),
+            "html" => Ok(ReportFormat::Html),
+            "svg" => Ok(ReportFormat::Svg),
+            x => Err(format!(
+                "unknown report format `{x}`, expecting `csv`, `json`, `html` or `svg`"
+            )),
+        }
+    }
+}
+
+/// Renders `report` with the writer of `format` and writes it to `path`.
+pub fn write(report: &Report, format: ReportFormat, path: &Path) -> Result<(), Error> {
+    let content = format.writer().render(report)?;
+    fs::write(path, content)?;
+    Ok(())
+}
+
+fn format_nanos(nanos: u64) -> String {
+    format!("{:.1?}", Duration::from_nanos(nanos))
+}
+
+fn format_optional(nanos: Option<u64>) -> String {
+    nanos.map_or_else(|| "-".into(), format_nanos)
+}
+
+pub struct CsvWriter;
+
+impl ReportWriter for CsvWriter {
+    fn render(&self, report: &Report) -> Result<String, Error> {
+        let cell = |value: Option<u64>| value.map(|x| x.to_string()).unwrap_or_default();
+
+        let mut csv =
+            String::from("year,day,parse_ns,part_1_ns,part_2_ns,total_ns,allocations,peak_bytes\n");
+
+        for day in &report.days {
+            let _ = writeln!(
+                csv,
+                "{},{},{},{},{},{},{},{}",
+                report.year,
+                day.day.into_inner(),
+                cell(day.parse),
+                cell(day.part_1),
+                cell(day.part_2),
+                day.total(),
+                cell(day.memory.map(|x| x.allocations)),
+                cell(day.memory.map(|x| x.peak_bytes)),
+            );
+        }
+
+        Ok(csv)
+    }
+}
+
+pub struct JsonWriter;
+
+impl ReportWriter for JsonWriter {
+    fn render(&self, report: &Report) -> Result<String, Error> {
+        serde_json::to_string_pretty(report).map_err(|e| Error::Parser(e.to_string()))
+    }
+}
+
+pub struct SvgWriter;
+
+impl SvgWriter {
+    const WIDTH: u64 = 640;
+    const ROW_HEIGHT: u64 = 22;
+    const LABEL_WIDTH: u64 = 64;
+    const VALUE_WIDTH: u64 = 80;
+    const COLORS: [(&'static str, &'static str); 3] = [
+        ("Parse", "#9e9e9e"),
+        ("Part 1", "#4e79a7"),
+        ("Part 2", "#f28e2b"),
+    ];
+}
+
+impl ReportWriter for SvgWriter {
+    /// One stacked bar per day, scaled to the slowest day.
+    fn render(&self, report: &Report) -> Result<String, Error> {
+        let bar_width = Self::WIDTH - Self::LABEL_WIDTH - Self::VALUE_WIDTH;
+        let max = report.days.iter().map(DayReport::total).max().unwrap_or(0);
+        let rows = report.days.len() as u64;
+        let height = (rows + 2) * Self::ROW_HEIGHT;
+
+        let mut svg = String::new();
+        let _ = writeln!(
+            svg,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{w}" height="{height}" viewBox="0 0 {w} {height}" font-family="sans-serif" font-size="12">"#,
+            w = Self::WIDTH
+        );
+        let _ = writeln!(
+            svg,
+            r#"<text x="0" y="14" font-weight="bold">Benchmarks {}</text>"#,
+            report.year
+        );
+
+        for (i, day) in report.days.iter().enumerate() {
+            let y = (i as u64 + 1) * Self::ROW_HEIGHT;
+            let _ = writeln!(
+                svg,
+                r#"<text x="0" y="{}">Day {}</text>"#,
+                y + 15,
+                day.day.into_inner()
+            );
+
+            let mut x = Self::LABEL_WIDTH;
+            for ((label, color), nanos) in
+                Self::COLORS.iter().zip([day.parse, day.part_1, day.part_2])
+            {
+                let Some(nanos) = nanos.filter(|_| max > 0) else {
+                    continue;
+                };
+                let width = nanos * bar_width / max;
+                let _ = writeln!(
+                    svg,
+                    r#"<rect x="{x}" y="{}" width="{width}" height="{}" fill="{color}"><title>Day {} {label}: {}</title></rect>"#,
+                    y + 3,
+                    Self::ROW_HEIGHT - 6,
+                    day.day.into_inner(),
+                    format_nanos(nanos)
+                );
+                x += width;
+            }
+
+            let _ = writeln!(
+                svg,
+                r#"<text x="{}" y="{}">{}</text>"#,
+                x + 4,
+                y + 15,
+                format_optional(Some(day.total()).filter(|x| *x > 0))
+            );
+        }
+
+        let y = (rows + 1) * Self::ROW_HEIGHT;
+        for (i, (label, color)) in Self::COLORS.iter().enumerate() {
+            let x = Self::LABEL_WIDTH + i as u64 * 80;
+            let _ = writeln!(
+                svg,
+                r#"<rect x="{x}" y="{}" width="12" height="12" fill="{color}"/><text x="{}" y="{}">{label}</text>"#,
+                y + 5,
+                x + 16,
+                y + 15
+            );
+        }
+
+        svg.push_str("</svg>\n");
+        Ok(svg)
+    }
+}
+
+pub struct HtmlWriter;
+
+impl ReportWriter for HtmlWriter {
+    /// A single page without external resources: a table of all timings and the SVG chart.
+    fn render(&self, report: &Report) -> Result<String, Error> {
+        let total = report.total();
+        let has_memory = report.days.iter().any(|day| day.memory.is_some());
+
+        let mut html = String::new();
+        let _ = writeln!(
+            html,
+            "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n<title>Benchmarks {}</title>",
+            report.year
+        );
+        html.push_str(
+            "<style>\nbody { font-family: sans-serif; margin: 2em; }\ntable { border-collapse: collapse; margin-bottom: 2em; }\nth, td { padding: 0.3em 0.8em; border-bottom: 1px solid #ddd; text-align: right; }\n</style>\n</head>\n<body>\n",
+        );
+        let _ = writeln!(html, "<h1>Benchmarks {}</h1>", report.year);
+
+        html.push_str("<table>\n<tr><th>Day</th><th>Parse</th><th>Part 1</th><th>Part 2</th><th>Total</th><th>Share</th>");
+        if has_memory {
+            html.push_str("<th>Allocations</th><th>Peak memory</th>");
+        }
+        html.push_str("</tr>\n");
+
+        for day in &report.days {
+            #[allow(clippy::cast_precision_loss)]
+            let share = if total > 0 {
+                format!("{:.1}%", day.total() as f64 / total as f64 * 100.0)
+            } else {
+                "-".into()
+            };
+
+            let _ = write!(
+                html,
+                "<tr><td>{}</td><td>{}</td><td>{}</td><td>{}</td><td>{}</td><td>{share}</td>",
+                day.day.into_inner(),
+                format_optional(day.parse),
+                format_optional(day.part_1),
+                format_optional(day.part_2),
+                format_optional(Some(day.total()).filter(|x| *x > 0)),
+            );
+            if has_memory {
+                let _ = write!(
+                    html,
+                    "<td>{}</td><td>{}</td>",
+                    day.memory
+                        .map_or_else(|| "-".into(), |x| x.allocations.to_string()),
+                    day.memory
+                        .map_or_else(|| "-".into(), |x| format_bytes(x.peak_bytes)),
+                );
+            }
+            html.push_str("</tr>\n");
+        }
+
+        let _ = writeln!(
+            html,
+            "</table>\n<p><strong>Total: {}</strong></p>",
+            format_nanos(total)
+        );
+        html.push_str(&SvgWriter.render(report)?);
+        html.push_str("</body>\n</html>\n");
+        Ok(html)
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{DayReport, Report, ReportFormat};
+    use crate::{day, year};
+
+    fn get_report() -> Report {
+        Report {
+            year: year!(2023),
+            days: vec![
+                DayReport {
+                    day: day!(1),
+                    parse: None,
+                    part_1: Some(1_000),
+                    part_2: Some(3_000),
+                    memory: None,
+                },
+                DayReport {
+                    day: day!(2),
+                    parse: Some(2_000),
+                    part_1: Some(4_000),
+                    part_2: None,
+                    memory: None,
+                },
+            ],
+        }
+    }
+
+    fn render(format: ReportFormat) -> String {
+        format.writer().render(&get_report()).unwrap()
+    }
+
+    #[test]
+    fn parses_report_formats() {
+        for format in ["csv", "json", "html", "svg"] {
+            assert_eq!(format.parse::<ReportFormat>().unwrap().to_string(), format);
+        }
+        assert!("md".parse::<ReportFormat>().is_err());
+        assert_eq!(
+            ReportFormat::Svg.default_path(year!(2023)).to_str(),
+            Some("benchmarks-2023.svg")
+        );
+    }
+
+    #[test]
+    fn renders_csv() {
+        assert_eq!(
+            render(ReportFormat::Csv),
+            [
+                "year,day,parse_ns,part_1_ns,part_2_ns,total_ns,allocations,peak_bytes",
+                "2023,1,,1000,3000,4000,,",
+                "2023,2,2000,4000,,6000,,",
+                "",
+            ]
+            .join("\n")
+        );
+    }
+
+    #[test]
+    fn renders_json() {
+        let json = render(ReportFormat::Json);
+        assert_eq!(serde_json::from_str::<Report>(&json).unwrap(), get_report());
+    }
+
+    #[test]
+    fn renders_svg() {
+        let svg = render(ReportFormat::Svg);
+        assert!(svg.starts_with("<svg"));
+        assert!(svg.ends_with("</svg>\n"));
+        // 4 bar segments and 3 legend entries.
+        assert_eq!(svg.matches("<rect").count(), 7);
+        // the segments of the slowest day span the bar area.
+        assert!(svg.contains(r##"<rect x="64" y="47" width="165" height="16" fill="#9e9e9e">"##));
+        assert!(svg.contains(r##"<rect x="229" y="47" width="330" height="16" fill="#4e79a7">"##));
+    }
+
+    #[test]
+    fn renders_html() {
+        let html = render(ReportFormat::Html);
+        assert!(html.starts_with("<!DOCTYPE html>"));
+        assert!(html.contains(
+            "<tr><td>2</td><td>2.0µs</td><td>4.0µs</td><td>-</td><td>6.0µs</td><td>60.0%</td></tr>"
+        ));
+        assert!(html.contains("<p><strong>Total: 10.0µs</strong></p>"));
+        assert!(html.contains("<svg"));
+        assert!(!html.contains("<th>Allocations</th>"));
+    }
+}
@@ -1,63 +1,533 @@
 /// Encapsulates code that interacts with solution functions.
-use crate::template::{aoc_cli, ANSI_ITALIC, ANSI_RESET};
-use crate::Day;
+use crate::template::alloc::{self, format_bytes, MemStats};
+use crate::template::aoc_client::{self, AocClientError, SubmissionOutcome};
+use crate::template::output::{out, outln};
+use crate::template::submissions::{self, Submission};
+use crate::template::{answers, config::config, OutputFormat, ANSI_ITALIC, ANSI_RESET};
+use crate::{Day, PuzzleId, Year};
+use serde::{Deserialize, Serialize};
+use std::any::Any;
 use std::fmt::Display;
-use std::io::{stdout, Write};
-use std::process::Output;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, RecvTimeoutError};
 use std::time::{Duration, Instant};
-use std::{cmp, env, process};
+use std::{cmp, env, process, thread};
 
 use super::ANSI_BOLD;
 
-pub fn run_part<I: Clone, T: Display>(func: impl Fn(I) -> Option<T>, input: I, day: Day, part: u8) {
+/// Options that control how a solution part is run and reported.
+#[derive(Debug, Clone, Copy)]
+pub struct RunOptions {
+    pub is_timed: bool,
+    /// Count the allocations of the first run of each part, needs the `mem` feature.
+    /// Only the thread running the part is counted, not threads that the solution spawns.
+    pub is_mem: bool,
+    pub format: OutputFormat,
+    /// Time limit of the first run of a part, [`None`] to wait indefinitely.
+    pub timeout: Option<Duration>,
+    /// Only run this part of each day, [`None`] runs both. Only used by `all`.
+    pub part: Option<u8>,
+    /// Set once a part exceeded its time limit, parts run with the same latch are no longer measured from then on.
+    /// Defaults to the latch of the process, see [`has_timed_out`].
+    pub timed_out: &'static AtomicBool,
+}
+
+impl Default for RunOptions {
+    fn default() -> Self {
+        Self {
+            is_timed: false,
+            is_mem: false,
+            format: OutputFormat::default(),
+            timeout: None,
+            part: None,
+            timed_out: &HAS_TIMED_OUT,
+        }
+    }
+}
+
+impl RunOptions {
+    /// Reads the options from the arguments of the current process.
+    /// The time limit is read from `--timeout <secs>`, falling back to `aoc.toml`.
+    /// Exits if `--mem` is passed to a binary built without the `mem` feature.
+    #[must_use]
+    pub fn from_args() -> Self {
+        let args: Vec<String> = env::args().collect();
+        let is_mem = args.iter().any(|x| x == "--mem");
+
+        if is_mem && !alloc::IS_INSTALLED {
+            eprintln!("Error: --mem needs the `mem` feature, run the day with `cargo solve <day> --mem` or `cargo run --features mem`.");
+            process::exit(1);
+        }
+
+        let timeout_secs = args
+            .iter()
+            .position(|x| x == "--timeout")
+            .and_then(|i| args.get(i + 1))
+            .and_then(|x| x.parse().ok());
+
+        Self {
+            is_timed: args.iter().any(|x| x == "--time"),
+            is_mem,
+            format: OutputFormat::from_args(),
+            timeout: config().run.timeout(timeout_secs),
+            ..Self::default()
+        }
+    }
+
+    #[must_use]
+    pub fn includes_part(&self, part: u8) -> bool {
+        self.part.is_none_or(|x| x == part)
+    }
+}
+
+/// Reasons why a solution part did not produce a result.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RunError {
+    TimedOut,
+    Panicked(String),
+}
+
+impl Display for RunError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RunError::TimedOut => write!(f, "timed out"),
+            RunError::Panicked(message) => write!(f, "panicked: {message}"),
+        }
+    }
+}
+
+/// Set once a part or parse stage exceeded its time limit. Its thread keeps running until the process exits,
+/// competing for the CPU and adding its allocations to every later measurement.
+static HAS_TIMED_OUT: AtomicBool = AtomicBool::new(false);
+
+/// Whether a part run with the default options timed out and is still running in the background.
+/// Its figures are not recorded from then on: later parts are neither benched nor memory-profiled.
+#[must_use]
+pub fn has_timed_out() -> bool {
+    HAS_TIMED_OUT.load(Ordering::Relaxed)
+}
+
+/// Run a solution part as configured by the command-line arguments, then submit it if requested.
+pub fn run_part<I, T, F>(func: F, input: I, puzzle: PuzzleId, part: u8) -> PartResult
+where
+    I: Clone + Send + 'static,
+    T: Display + Send + 'static,
+    F: Fn(I) -> Option<T> + Copy + Send + 'static,
+{
+    let result = run_part_with(func, input, puzzle, part, RunOptions::from_args());
+
+    if let Some(answer) = &result.answer {
+        submit_result(answer, puzzle, part);
+    }
+
+    result
+}
+
+/// Run a solution part and print its result in the requested format.
+/// A part that panics or exceeds the time limit is reported as failed instead of aborting the runner.
+pub fn run_part_with<I, T, F>(
+    func: F,
+    input: I,
+    puzzle: PuzzleId,
+    part: u8,
+    options: RunOptions,
+) -> PartResult
+where
+    I: Clone + Send + 'static,
+    T: Display + Send + 'static,
+    F: Fn(I) -> Option<T> + Copy + Send + 'static,
+{
     let part_str = format!("Part {part}");
 
-    let (result, duration, samples) =
-        run_timed(func, input, |result| print_result(result, &part_str, ""));
+    let run = run_timed(func, input, options, |result| {
+        if options.format == OutputFormat::Text {
+            print_result(result, &part_str, "");
+        }
+    });
 
-    print_result(&result, &part_str, &format_duration(&duration, samples));
+    let (result, stats, mem) = match run {
+        Ok(run) => run,
+        Err(e) => {
+            let record = PartResult::failed(puzzle, part, &e);
+            print_part_record(&record, options.format);
+            return record;
+        }
+    };
+
+    let mut record = PartResult::new(puzzle, part, result.as_ref(), stats);
+    record.mem = mem;
+
+    match answers::read(puzzle) {
+        Ok(answers) => record.check(&answers),
+        Err(e) => eprintln!("Could not check result against stored answer: {e}"),
+    }
+
+    print_part_record(&record, options.format);
+
+    record
+}
+
+/// Run the parse stage of a day as configured by the command-line arguments.
+/// Returns the parsed input, or [`None`] if parsing failed.
+pub fn run_parse<I, P, F>(func: F, input: I, puzzle: PuzzleId) -> Option<&'static P>
+where
+    I: Clone + Send + 'static,
+    P: Send + Sync + 'static,
+    F: Fn(I) -> P + Copy + Send + 'static,
+{
+    run_parse_with(func, input, puzzle, RunOptions::from_args()).0
+}
+
+/// Run the parse stage of a day and print its timing in the requested format.
+/// The parsed input is shared by both parts, so it is timed on its own.
+///
+/// The parsed input is leaked: a part that exceeds the time limit keeps running on its own thread and may still reference it.
+pub fn run_parse_with<I, P, F>(
+    func: F,
+    input: I,
+    puzzle: PuzzleId,
+    options: RunOptions,
+) -> (Option<&'static P>, ParseResult)
+where
+    I: Clone + Send + 'static,
+    P: Send + Sync + 'static,
+    F: Fn(I) -> P + Copy + Send + 'static,
+{
+    let run = run_timed(func, input, options, |_| {
+        if options.format == OutputFormat::Text {
+            out!("Parse:");
+        }
+    });
 
-    if let Some(result) = result {
-        submit_result(result, day, part);
+    let mut record = ParseResult {
+        year: puzzle.year,
+        day: puzzle.day,
+        stats: Stats::single(Duration::ZERO),
+        mem: None,
+        error: None,
+    };
+
+    let parsed = match run {
+        Ok((parsed, stats, mem)) => {
+            record.stats = stats;
+            record.mem = mem;
+            Some(&*Box::leak(Box::new(parsed)))
+        }
+        Err(e) => {
+            record.error = Some(e.to_string());
+            None
+        }
+    };
+
+    print_parse_record(&record, options.format);
+
+    (parsed, record)
+}
+
+/// Prints the result of a part in the requested format, see [`run_part_with`].
+pub(crate) fn print_part_record(record: &PartResult, format: OutputFormat) {
+    let part_str = format!("Part {}", record.part);
+
+    match (format, &record.error) {
+        (OutputFormat::Text, Some(e)) => outln!("{part_str}: {ANSI_ITALIC}{e}{ANSI_RESET}"),
+        (OutputFormat::Text, None) => print_result(
+            &record.answer,
+            &part_str,
+            &format!(
+                "{}{}{}",
+                format_check(record),
+                format_duration(&record.stats),
+                record.mem.as_ref().map(format_memory).unwrap_or_default()
+            ),
+        ),
+        (OutputFormat::Json, _) => print_record(record),
+    }
+}
+
+/// Prints the timing of a parse stage in the requested format, see [`run_parse_with`].
+pub(crate) fn print_parse_record(record: &ParseResult, format: OutputFormat) {
+    match (format, &record.error) {
+        (OutputFormat::Text, Some(e)) => outln!("Parse: {ANSI_ITALIC}{e}{ANSI_RESET}"),
+        (OutputFormat::Text, None) => outln!(
+            "\rParse:{}{}",
+            format_duration(&record.stats),
+            record.mem.as_ref().map(format_memory).unwrap_or_default()
+        ),
+        (OutputFormat::Json, _) => print_record(record),
+    }
+}
+
+/// Results of running both parts of a day, see [`crate::template::registry::Solution::run`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DayResult {
+    /// Timing of the parse stage, [`None`] if the solution does not declare one.
+    pub parse: Option<ParseResult>,
+    pub parts: Vec<PartResult>,
+}
+
+/// Timing of the parse stage of a day, emitted as a single JSON line before the parts with `--format json`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ParseResult {
+    pub year: Year,
+    pub day: Day,
+    pub stats: Stats,
+    /// Allocation figures of the first run, only present with `--mem`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mem: Option<MemStats>,
+    /// Why parsing failed, see [`RunError`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// Structured result of running one solution part, emitted as a single JSON line with `--format json`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PartResult {
+    pub year: Year,
+    pub day: Day,
+    pub part: u8,
+    pub answer: Option<String>,
+    pub success: bool,
+    pub stats: Stats,
+    /// The stored answer for this part, see [`answers`].
+    pub expected: Option<String>,
+    /// Whether `answer` matches `expected`, [`None`] if no answer is stored.
+    pub correct: Option<bool>,
+    /// Allocation figures of the first run, only present with `--mem`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mem: Option<MemStats>,
+    /// Why the part did not produce a result, see [`RunError`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl PartResult {
+    fn new<T: Display>(puzzle: PuzzleId, part: u8, answer: Option<&T>, stats: Stats) -> Self {
+        Self {
+            year: puzzle.year,
+            day: puzzle.day,
+            part,
+            answer: answer.map(ToString::to_string),
+            success: answer.is_some(),
+            stats,
+            expected: None,
+            correct: None,
+            mem: None,
+            error: None,
+        }
+    }
+
+    fn failed(puzzle: PuzzleId, part: u8, error: &RunError) -> Self {
+        let mut result = Self::new::<String>(puzzle, part, None, Stats::single(Duration::ZERO));
+        result.error = Some(error.to_string());
+        result
+    }
+
+    #[must_use]
+    pub fn puzzle(&self) -> PuzzleId {
+        PuzzleId::__new_unchecked(self.year, self.day)
+    }
+
+    /// Checks the answer against the stored answers of its day.
+    pub fn check(&mut self, answers: &answers::Answers) {
+        self.expected = answers.get(self.part).map(Into::into);
+        self.correct = answers.check(self.part, self.answer.as_deref());
+    }
+}
+
+/// Summary statistics over the samples collected while benching a solution part.
+/// Durations serialize as integer nanoseconds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Stats {
+    #[serde(with = "duration_nanos")]
+    pub mean: Duration,
+    #[serde(with = "duration_nanos")]
+    pub median: Duration,
+    #[serde(with = "duration_nanos")]
+    pub min: Duration,
+    #[serde(with = "duration_nanos")]
+    pub max: Duration,
+    #[serde(with = "duration_nanos")]
+    pub std_dev: Duration,
+    #[serde(with = "duration_nanos")]
+    pub p95: Duration,
+    #[serde(with = "duration_nanos")]
+    pub p99: Duration,
+    /// Number of samples outside of the Tukey fences (1.5 × IQR beyond the quartiles).
+    pub outliers: usize,
+    pub samples: u128,
+}
+
+impl Stats {
+    /// Stats for a part that was executed exactly once.
+    pub(crate) fn single(duration: Duration) -> Self {
+        Self {
+            mean: duration,
+            median: duration,
+            min: duration,
+            max: duration,
+            std_dev: Duration::ZERO,
+            p95: duration,
+            p99: duration,
+            outliers: 0,
+            samples: 1,
+        }
+    }
+
+    /// Computes stats from a list of samples. Sorts `timers` in place.
+    fn from_samples(timers: &mut [Duration]) -> Self {
+        assert!(!timers.is_empty(), "cannot compute stats without samples");
+
+        timers.sort_unstable();
+
+        let nanos: Vec<f64> = timers.iter().map(|d| d.as_nanos() as f64).collect();
+        let len = nanos.len() as f64;
+
+        let mean = nanos.iter().sum::<f64>() / len;
+        let variance = nanos.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / len;
+
+        let q1 = quantile(&nanos, 0.25);
+        let q3 = quantile(&nanos, 0.75);
+        let iqr = q3 - q1;
+        let (lower_fence, upper_fence) = (q1 - 1.5 * iqr, q3 + 1.5 * iqr);
+
+        Self {
+            mean: nanos_to_duration(mean),
+            median: nanos_to_duration(quantile(&nanos, 0.5)),
+            min: timers[0],
+            max: timers[timers.len() - 1],
+            std_dev: nanos_to_duration(variance.sqrt()),
+            p95: percentile(timers, 95),
+            p99: percentile(timers, 99),
+            outliers: nanos
+                .iter()
+                .filter(|&&x| x < lower_fence || x > upper_fence)
+                .count(),
+            samples: timers.len() as u128,
+        }
     }
 }
 
 /// Run a solution part. The behavior differs depending on whether we are running a release or debug build:
 ///  1. in debug, the function is executed once.
 ///  2. in release, the function is benched (approx. 1 second of execution time or 10 samples, whatever take longer.)
-fn run_timed<I: Clone, T>(
-    func: impl Fn(I) -> T,
+///
+/// With `--mem`, the allocations of the first run are counted. Its timing includes the overhead of counting.
+/// The function runs on a separate thread, see [`run_isolated`]. After a timeout, see [`RunOptions::timed_out`], it is no longer benched or profiled.
+fn run_timed<I, T, F>(
+    func: F,
     input: I,
+    options: RunOptions,
     hook: impl Fn(&T),
-) -> (T, Duration, u128) {
-    let timer = Instant::now();
-    let result = func(input.clone());
-    let base_time = timer.elapsed();
+) -> Result<(T, Stats, Option<MemStats>), RunError>
+where
+    I: Clone + Send + 'static,
+    T: Send + 'static,
+    F: Fn(I) -> T + Copy + Send + 'static,
+{
+    let first_input = input.clone();
+    let is_measured = !options.timed_out.load(Ordering::Relaxed);
+    let is_mem = options.is_mem && is_measured;
+
+    let ((result, base_time), mem) = run_isolated(
+        move || {
+            let run = || {
+                let timer = Instant::now();
+                let result = func(first_input);
+                (result, timer.elapsed())
+            };
+
+            if is_mem {
+                let (run, mem) = alloc::measure(run);
+                (run, Some(mem))
+            } else {
+                (run(), None)
+            }
+        },
+        options.timeout,
+    )
+    .inspect_err(|e| {
+        if *e == RunError::TimedOut
+            && !options.timed_out.swap(true, Ordering::Relaxed)
+            && (options.is_timed || options.is_mem)
+        {
+            eprintln!("A part timed out and keeps running in the background, timings and memory figures of the remaining parts are not recorded.");
+        }
+    })?;
 
     hook(&result);
 
-    let run = if std::env::args().any(|x| x == "--time") {
-        bench(func, input, &base_time)
+    let stats = if options.is_timed && is_measured {
+        // the first run finished in time, so benching is not limited.
+        let format = options.format;
+        run_isolated(move || bench(func, input, &base_time, format), None)?
     } else {
-        (base_time, 1)
+        Stats::single(base_time)
     };
 
-    (result, run.0, run.1)
+    Ok((result, stats, mem))
 }
 
-fn bench<I: Clone, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) -> (Duration, u128) {
-    let mut stdout = stdout();
+/// Solutions often recurse deeply, give them as much stack as the main thread usually has.
+const STACK_SIZE: usize = 64 * 1024 * 1024;
 
-    print!(" > {ANSI_ITALIC}benching{ANSI_RESET}");
-    let _ = stdout.flush();
+/// Runs `func` on its own thread, so a panic or an endless loop does not take down the runner.
+/// A thread that exceeds `timeout` is left running in the background until the process exits.
+pub(crate) fn run_isolated<R: Send + 'static>(
+    func: impl FnOnce() -> R + Send + 'static,
+    timeout: Option<Duration>,
+) -> Result<R, RunError> {
+    let (tx, rx) = mpsc::channel();
 
-    let bench_iterations = cmp::min(
-        10000,
-        cmp::max(
-            Duration::from_secs(1).as_nanos() / cmp::max(base_time.as_nanos(), 10),
-            10,
-        ),
-    );
+    thread::Builder::new()
+        .name("solution".into())
+        .stack_size(STACK_SIZE)
+        .spawn(move || {
+            let _ = tx.send(panic::catch_unwind(AssertUnwindSafe(func)));
+        })
+        .map_err(|e| RunError::Panicked(format!("could not spawn thread: {e}")))?;
+
+    let received = match timeout {
+        Some(timeout) => rx.recv_timeout(timeout).map_err(|e| match e {
+            RecvTimeoutError::Timeout => RunError::TimedOut,
+            RecvTimeoutError::Disconnected => RunError::Panicked("thread exited".into()),
+        })?,
+        None => rx
+            .recv()
+            .map_err(|_| RunError::Panicked("thread exited".into()))?,
+    };
+
+    received.map_err(|payload| RunError::Panicked(panic_message(payload.as_ref())))
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        (*message).to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "unknown cause".into()
+    }
+}
+
+fn bench<I: Clone, T>(
+    func: impl Fn(I) -> T,
+    input: I,
+    base_time: &Duration,
+    format: OutputFormat,
+) -> Stats {
+    if format == OutputFormat::Text {
+        out!(" > {ANSI_ITALIC}benching{ANSI_RESET}");
+    }
+
+    let bench_iterations = config().bench.samples(*base_time);
+
+    // discard a few runs up front so that cold caches and lazy allocations do not skew the samples.
+    let warmup_iterations = cmp::max(bench_iterations / 10, 1);
+
+    for _ in 0..warmup_iterations {
+        func(input.clone());
+    }
 
     let mut timers: Vec<Duration> = vec![];
 
@@ -69,30 +539,91 @@ fn bench<I: Clone, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) ->
         timers.push(timer.elapsed());
     }
 
-    (
+    Stats::from_samples(&mut timers)
+}
+
+/// Linearly interpolated quantile of an already sorted, non-empty slice.
+fn quantile(sorted: &[f64], q: f64) -> f64 {
+    let pos = q * (sorted.len() - 1) as f64;
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    let (lower, upper) = (pos.floor() as usize, pos.ceil() as usize);
+    sorted[lower] + (sorted[upper] - sorted[lower]) * (pos - lower as f64)
+}
+
+/// Nearest-rank percentile of an already sorted, non-empty slice.
+fn percentile(sorted: &[Duration], p: usize) -> Duration {
+    let rank = (p * sorted.len()).div_ceil(100);
+    sorted[rank.saturating_sub(1)]
+}
+
+fn nanos_to_duration(nanos: f64) -> Duration {
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    Duration::from_nanos(nanos.round() as u64)
+}
+
+/// (De)serializes a [`Duration`] as integer nanoseconds.
+mod duration_nanos {
+    use serde::{Deserialize, Deserializer, Serializer};
+    use std::time::Duration;
+
+    #[allow(clippy::trivially_copy_pass_by_ref)]
+    pub fn serialize<S: Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
         #[allow(clippy::cast_possible_truncation)]
-        Duration::from_nanos(average_duration(&timers) as u64),
-        bench_iterations,
-    )
+        serializer.serialize_u64(duration.as_nanos() as u64)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Duration, D::Error> {
+        u64::deserialize(deserializer).map(Duration::from_nanos)
+    }
 }
 
-fn average_duration(numbers: &[Duration]) -> u128 {
-    numbers
-        .iter()
-        .map(std::time::Duration::as_nanos)
-        .sum::<u128>()
-        / numbers.len() as u128
+pub(crate) fn format_check(result: &PartResult) -> String {
+    match (result.correct, &result.expected) {
+        (Some(true), _) => " ✔".into(),
+        (Some(false), Some(expected)) if !expected.contains('\n') => {
+            format!(" ✘ (expected {expected})")
+        }
+        (Some(false), _) => " ✘".into(),
+        (None, _) => String::new(),
+    }
 }
 
-fn format_duration(duration: &Duration, samples: u128) -> String {
-    if samples == 1 {
-        format!(" ({duration:.1?})")
+pub(crate) fn format_memory(mem: &MemStats) -> String {
+    format!(
+        " [{} allocs, {} allocated, {} peak]",
+        mem.allocations,
+        format_bytes(mem.bytes),
+        format_bytes(mem.peak_bytes)
+    )
+}
+
+pub(crate) fn format_duration(stats: &Stats) -> String {
+    if stats.samples == 1 {
+        format!(" ({:.1?})", stats.mean)
     } else {
-        format!(" ({duration:.1?} @ {samples} samples)")
+        format!(
+            " ({:.1?} @ {} samples, median {:.1?}, min {:.1?}, max {:.1?}, σ {:.1?}, p95 {:.1?}, p99 {:.1?}, {} outliers)",
+            stats.mean,
+            stats.samples,
+            stats.median,
+            stats.min,
+            stats.max,
+            stats.std_dev,
+            stats.p95,
+            stats.p99,
+            stats.outliers
+        )
     }
 }
 
-fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str) {
+fn print_record<T: Serialize>(record: &T) {
+    match serde_json::to_string(record) {
+        Ok(json) => outln!("{json}"),
+        Err(e) => eprintln!("Failed to serialize result: {e}"),
+    }
+}
+
+pub(crate) fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str) {
     let is_intermediate_result = duration_str.is_empty();
 
     match result {
@@ -100,41 +631,39 @@ fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str)
             if result.to_string().contains('\n') {
                 let str = format!("{part}: ▼ {duration_str}");
                 if is_intermediate_result {
-                    print!("{str}");
+                    out!("{str}");
                 } else {
-                    print!("\r");
-                    println!("{str}");
-                    println!("{result}");
+                    out!("\r");
+                    outln!("{str}");
+                    outln!("{result}");
                 }
             } else {
                 let str = format!("{part}: {ANSI_BOLD}{result}{ANSI_RESET}{duration_str}");
                 if is_intermediate_result {
-                    print!("{str}");
+                    out!("{str}");
                 } else {
-                    print!("\r");
-                    println!("{str}");
+                    out!("\r");
+                    outln!("{str}");
                 }
             }
         }
         None => {
             if is_intermediate_result {
-                print!("{part}: ✖");
+                out!("{part}: ✖");
             } else {
-                print!("\r");
-                println!("{part}: ✖             ");
+                out!("\r");
+                outln!("{part}: ✖             ");
             }
         }
     }
 }
 
-/// Parse the arguments passed to `solve` and try to submit one part of the solution if:
-///  1. we are in `--release` mode.
-///  2. aoc-cli is installed.
-fn submit_result<T: Display>(
-    result: T,
-    day: Day,
+/// Parse the arguments passed to `solve` and try to submit one part of the solution if requested.
+fn submit_result(
+    result: &str,
+    puzzle: PuzzleId,
     part: u8,
-) -> Option<Result<Output, aoc_cli::AocCommandError>> {
+) -> Option<Result<SubmissionOutcome, AocClientError>> {
     let args: Vec<String> = env::args().collect();
 
     if !args.contains(&"--submit".into()) {
@@ -157,11 +686,232 @@ fn submit_result<T: Display>(
         return None;
     }
 
-    if aoc_cli::check().is_err() {
-        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
+    let (log, wait_until) = match (
+        submissions::read(puzzle),
+        submissions::read_wait_until(puzzle.year),
+    ) {
+        (Ok(log), Ok(wait_until)) => (log, wait_until),
+        (Err(e), _) | (_, Err(e)) => {
+            eprintln!("Not submitting: {e}");
+            process::exit(1);
+        }
+    };
+
+    if let Err(refusal) = submissions::check(&log, part, result)
+        .and_then(|()| submissions::check_cooldown(wait_until, submissions::now()))
+    {
+        eprintln!("Not submitting: {refusal}");
         process::exit(1);
     }
 
-    println!("Submitting result via aoc-cli...");
-    Some(aoc_cli::submit(day, part, &result.to_string()))
+    println!("Submitting result...");
+    let outcome = aoc_client::submit(puzzle, part, result);
+
+    match &outcome {
+        Ok(outcome) => {
+            println!("{outcome}");
+            let submission = Submission::new(part, result, *outcome, submissions::now());
+            if let Err(e) = submissions::append(puzzle, &submission) {
+                eprintln!("Failed to log submission: {e}");
+            }
+        }
+        Err(e) => eprintln!("Failed to submit result: {e}"),
+    }
+
+    if matches!(outcome, Ok(SubmissionOutcome::Correct)) {
+        match answers::record(puzzle, part, result) {
+            Ok(()) => println!(
+                "🎄 Recorded answer in \"{}\".",
+                answers::get_answers_path(puzzle).display()
+            ),
+            Err(e) => eprintln!("Failed to record answer: {e}"),
+        }
+    }
+
+    Some(outcome)
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{
+        format_check, format_duration, has_timed_out, run_isolated, run_part_with, PartResult,
+        RunError, RunOptions, Stats,
+    };
+    use crate::puzzle;
+    use crate::template::{answers::Answers, output::capture, OutputFormat};
+    use std::{
+        sync::atomic::{AtomicBool, Ordering},
+        thread,
+        time::Duration,
+    };
+
+    fn nanos(values: &[u64]) -> Vec<Duration> {
+        values.iter().map(|&x| Duration::from_nanos(x)).collect()
+    }
+
+    #[test]
+    fn stats_from_samples() {
+        let mut timers = nanos(&[50, 10, 40, 20, 30]);
+        let stats = Stats::from_samples(&mut timers);
+        assert_eq!(stats.mean, Duration::from_nanos(30));
+        assert_eq!(stats.median, Duration::from_nanos(30));
+        assert_eq!(stats.min, Duration::from_nanos(10));
+        assert_eq!(stats.max, Duration::from_nanos(50));
+        assert_eq!(stats.std_dev, Duration::from_nanos(14));
+        assert_eq!(stats.p95, Duration::from_nanos(50));
+        assert_eq!(stats.p99, Duration::from_nanos(50));
+        assert_eq!(stats.outliers, 0);
+        assert_eq!(stats.samples, 5);
+    }
+
+    #[test]
+    fn stats_median_of_even_samples() {
+        let mut timers = nanos(&[10, 20, 30, 40]);
+        assert_eq!(
+            Stats::from_samples(&mut timers).median,
+            Duration::from_nanos(25)
+        );
+    }
+
+    #[test]
+    fn stats_detects_outliers() {
+        let mut timers = nanos(&[100, 101, 102, 99, 98, 100, 5000]);
+        let stats = Stats::from_samples(&mut timers);
+        assert_eq!(stats.outliers, 1);
+        assert_eq!(stats.max, Duration::from_nanos(5000));
+        assert_eq!(stats.median, Duration::from_nanos(100));
+    }
+
+    #[test]
+    fn stats_percentiles() {
+        let mut timers: Vec<Duration> = (1..=100).map(Duration::from_nanos).collect();
+        let stats = Stats::from_samples(&mut timers);
+        assert_eq!(stats.p95, Duration::from_nanos(95));
+        assert_eq!(stats.p99, Duration::from_nanos(99));
+    }
+
+    #[test]
+    fn format_single_run() {
+        let stats = Stats::single(Duration::from_micros(12));
+        assert_eq!(format_duration(&stats), " (12.0µs)");
+    }
+
+    #[test]
+    fn part_result_round_trips_as_json() {
+        let stats = Stats::single(Duration::from_micros(12));
+        let record = PartResult::new(puzzle!(2023, 3), 2, Some(&42), stats);
+        let json = serde_json::to_string(&record).unwrap();
+        assert!(json.starts_with(
+            r#"{"year":2023,"day":3,"part":2,"answer":"42","success":true,"stats":{"mean":12000,"#
+        ));
+        assert_eq!(serde_json::from_str::<PartResult>(&json).unwrap(), record);
+    }
+
+    #[test]
+    fn part_result_without_answer() {
+        let stats = Stats::single(Duration::from_micros(12));
+        let record = PartResult::new::<u32>(puzzle!(2023, 3), 1, None, stats);
+        assert!(!record.success);
+        assert_eq!(record.answer, None);
+    }
+
+    #[test]
+    fn part_result_checks_answers() {
+        let stats = Stats::single(Duration::from_micros(12));
+        let mut answers = Answers::default();
+        answers.set(1, "42");
+
+        let mut record = PartResult::new(puzzle!(2023, 3), 1, Some(&42), stats);
+        record.check(&answers);
+        assert_eq!(record.correct, Some(true));
+        assert_eq!(format_check(&record), " ✔");
+
+        let mut record = PartResult::new(puzzle!(2023, 3), 1, Some(&41), stats);
+        record.check(&answers);
+        assert_eq!(record.correct, Some(false));
+        assert_eq!(format_check(&record), " ✘ (expected 42)");
+
+        let mut record = PartResult::new(puzzle!(2023, 3), 2, Some(&41), stats);
+        record.check(&answers);
+        assert_eq!(record.correct, None);
+        assert_eq!(format_check(&record), "");
+    }
+
+    #[test]
+    fn isolates_panics() {
+        let result = run_isolated(|| -> u32 { panic!("boom") }, None);
+        assert_eq!(result, Err(RunError::Panicked("boom".into())));
+        assert_eq!(
+            run_isolated(|| panic!("{} {}", "formatted", 1), None).map(|()| ()),
+            Err(RunError::Panicked("formatted 1".into()))
+        );
+        assert_eq!(run_isolated(|| 42, None), Ok(42));
+    }
+
+    #[test]
+    fn stops_waiting_after_timeout() {
+        let result = run_isolated(
+            || thread::sleep(Duration::from_secs(5)),
+            Some(Duration::from_millis(10)),
+        );
+        assert_eq!(result, Err(RunError::TimedOut));
+        assert_eq!(RunError::TimedOut.to_string(), "timed out");
+    }
+
+    #[test]
+    fn reports_failed_parts() {
+        let options = RunOptions {
+            is_timed: false,
+            is_mem: false,
+            format: OutputFormat::Json,
+            ..RunOptions::default()
+        };
+        let (record, output) = capture(|| {
+            run_part_with(
+                |_: &str| -> Option<u32> { panic!("boom") },
+                "",
+                puzzle!(2023, 3),
+                1,
+                options,
+            )
+        });
+        assert!(output.contains(r#""error":"panicked: boom""#));
+        assert!(!record.success);
+        assert_eq!(record.answer, None);
+        assert_eq!(record.error.as_deref(), Some("panicked: boom"));
+    }
+
+    #[test]
+    fn stops_measuring_after_timeout() {
+        // a latch of its own, so that other tests are still measured.
+        static TIMED_OUT: AtomicBool = AtomicBool::new(false);
+
+        let options = RunOptions {
+            is_mem: true,
+            format: OutputFormat::Json,
+            timeout: Some(Duration::from_millis(10)),
+            timed_out: &TIMED_OUT,
+            ..RunOptions::default()
+        };
+        let (record, _) = capture(|| {
+            run_part_with(
+                |_: &str| -> Option<u32> {
+                    thread::sleep(Duration::from_secs(5));
+                    None
+                },
+                "",
+                puzzle!(2023, 3),
+                1,
+                options,
+            )
+        });
+        assert_eq!(record.error.as_deref(), Some("timed out"));
+        assert!(TIMED_OUT.load(Ordering::Relaxed));
+        assert!(!has_timed_out());
+
+        let (record, _) =
+            capture(|| run_part_with(|_: &str| Some(42), "", puzzle!(2023, 3), 2, options));
+        assert_eq!(record.answer.as_deref(), Some("42"));
+        assert_eq!(record.mem, None);
+    }
 }
@@ -0,0 +1,351 @@
+/// Module that logs every submitted answer and its verdict in `data/{year}/submissions/{day}.jsonl`,
+/// and uses that log to refuse submissions that are known to be wrong before they are sent.
+use std::{
+    fmt::Display,
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    path::PathBuf,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::template::aoc_client::SubmissionOutcome;
+use crate::template::get_data_path;
+use crate::{PuzzleId, Year};
+
+#[derive(Debug)]
+pub enum Error {
+    Parser(String),
+    IO(io::Error),
+}
+
+impl From<std::io::Error> for Error {
+    fn from(e: std::io::Error) -> Self {
+        Error::IO(e)
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Parser(e) => write!(f, "could not parse submission log: {e}"),
+            Error::IO(e) => write!(f, "could not access submission log: {e}"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    Incorrect,
+    RateLimited,
+    AlreadySolved,
+}
+
+impl Verdict {
+    /// Whether the server checked the answer and rejected it.
+    #[must_use]
+    pub fn is_wrong(self) -> bool {
+        matches!(
+            self,
+            Verdict::TooHigh | Verdict::TooLow | Verdict::Incorrect
+        )
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            Verdict::Correct => "correct",
+            Verdict::TooHigh => "too high",
+            Verdict::TooLow => "too low",
+            Verdict::Incorrect => "incorrect",
+            Verdict::RateLimited => "rate-limited",
+            Verdict::AlreadySolved => "already solved",
+        };
+        write!(f, "{s}")
+    }
+}
+
+/// A submitted answer and the response of the server.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Submission {
+    pub part: u8,
+    pub answer: String,
+    pub verdict: Verdict,
+    /// Unix timestamp in seconds.
+    pub timestamp: u64,
+    /// Unix timestamp in seconds until which the server does not accept another submission.
+    pub wait_until: Option<u64>,
+}
+
+impl Submission {
+    #[must_use]
+    pub fn new(part: u8, answer: &str, outcome: SubmissionOutcome, timestamp: u64) -> Self {
+        let (verdict, wait) = match outcome {
+            SubmissionOutcome::Correct => (Verdict::Correct, None),
+            SubmissionOutcome::TooHigh { wait } => (Verdict::TooHigh, wait),
+            SubmissionOutcome::TooLow { wait } => (Verdict::TooLow, wait),
+            SubmissionOutcome::Incorrect { wait } => (Verdict::Incorrect, wait),
+            SubmissionOutcome::RateLimited { wait } => (Verdict::RateLimited, Some(wait)),
+            SubmissionOutcome::AlreadySolved => (Verdict::AlreadySolved, None),
+        };
+
+        Self {
+            part,
+            answer: answer.trim().to_string(),
+            verdict,
+            timestamp,
+            wait_until: wait.map(|wait| timestamp + wait.as_secs()),
+        }
+    }
+}
+
+/// Reasons to not submit an answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Refusal {
+    /// The answer is empty or `0`, which is what unfinished solutions usually return.
+    Placeholder,
+    /// The part was already answered correctly.
+    AlreadySolved(String),
+    /// The same answer was submitted before and rejected.
+    KnownWrong,
+    /// A lower or equal answer was already rejected as too high.
+    AboveUpperBound(i128),
+    /// A higher or equal answer was already rejected as too low.
+    BelowLowerBound(i128),
+    /// The server asked to wait before submitting again.
+    Cooldown(Duration),
+}
+
+impl Display for Refusal {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Refusal::Placeholder => write!(f, "the answer looks like a placeholder."),
+            Refusal::AlreadySolved(answer) => {
+                write!(f, "this part was already solved with answer {answer}.")
+            }
+            Refusal::KnownWrong => write!(f, "this answer was already submitted and is wrong."),
+            Refusal::AboveUpperBound(bound) => {
+                write!(
+                    f,
+                    "the answer must be lower than {bound}, which is too high."
+                )
+            }
+            Refusal::BelowLowerBound(bound) => {
+                write!(
+                    f,
+                    "the answer must be higher than {bound}, which is too low."
+                )
+            }
+            Refusal::Cooldown(wait) => write!(
+                f,
+                "the server asked to wait, try again in {}s.",
+                wait.as_secs()
+            ),
+        }
+    }
+}
+
+/// Checks an answer for one part against previous submissions of the day.
+/// The cooldown is not part of it, as it applies to submissions for every day, see [`check_cooldown`].
+pub fn check(log: &[Submission], part: u8, answer: &str) -> Result<(), Refusal> {
+    let answer = answer.trim();
+
+    if answer.is_empty() || answer == "0" {
+        return Err(Refusal::Placeholder);
+    }
+
+    let submissions = log.iter().filter(|s| s.part == part);
+
+    if let Some(correct) = submissions.clone().find(|s| s.verdict == Verdict::Correct) {
+        return Err(Refusal::AlreadySolved(correct.answer.clone()));
+    }
+
+    if submissions
+        .clone()
+        .any(|s| s.verdict.is_wrong() && s.answer == answer)
+    {
+        return Err(Refusal::KnownWrong);
+    }
+
+    if let Ok(value) = answer.parse::<i128>() {
+        let bound = |verdict: Verdict| {
+            submissions
+                .clone()
+                .filter(move |s| s.verdict == verdict)
+                .filter_map(|s| s.answer.parse::<i128>().ok())
+        };
+
+        if let Some(upper) = bound(Verdict::TooHigh).min() {
+            if value >= upper {
+                return Err(Refusal::AboveUpperBound(upper));
+            }
+        }
+
+        if let Some(lower) = bound(Verdict::TooLow).max() {
+            if value <= lower {
+                return Err(Refusal::BelowLowerBound(lower));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Checks whether the server still asks to wait, `wait_until` is the latest cooldown of any day, see [`read_wait_until`].
+pub fn check_cooldown(wait_until: Option<u64>, now: u64) -> Result<(), Refusal> {
+    match wait_until {
+        Some(wait_until) if wait_until > now => {
+            Err(Refusal::Cooldown(Duration::from_secs(wait_until - now)))
+        }
+        _ => Ok(()),
+    }
+}
+
+/// The current time as unix timestamp in seconds.
+#[must_use]
+pub fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs())
+}
+
+#[must_use]
+pub fn get_log_path(puzzle: PuzzleId) -> PathBuf {
+    get_data_path("submissions", puzzle)
+}
+
+fn parse(s: &str) -> Result<Vec<Submission>, Error> {
+    s.lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| serde_json::from_str(line).map_err(|e| Error::Parser(e.to_string())))
+        .collect()
+}
+
+/// Reads all submissions of a day, oldest first.
+pub fn read(puzzle: PuzzleId) -> Result<Vec<Submission>, Error> {
+    let path = get_log_path(puzzle);
+    if !path.exists() {
+        return Ok(vec![]);
+    }
+    parse(&fs::read_to_string(path)?)
+}
+
+/// Reads the latest time until which the server asked to wait from the logs of every day of a year.
+/// The server limits submissions of the whole account, so a cooldown caused by one day applies to the others as well.
+pub fn read_wait_until(year: Year) -> Result<Option<u64>, Error> {
+    let mut wait_until = None;
+
+    for day in year.days() {
+        let log = read(PuzzleId::__new_unchecked(year, day))?;
+        wait_until = wait_until.max(log.iter().filter_map(|s| s.wait_until).max());
+    }
+
+    Ok(wait_until)
+}
+
+/// Appends a submission to the log of a day.
+pub fn append(puzzle: PuzzleId, submission: &Submission) -> Result<(), Error> {
+    let path = get_log_path(puzzle);
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    let line = serde_json::to_string(submission).map_err(|e| Error::Parser(e.to_string()))?;
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    writeln!(file, "{line}")?;
+    Ok(())
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{check, check_cooldown, parse, Refusal, Submission, Verdict};
+    use crate::template::aoc_client::SubmissionOutcome;
+    use std::time::Duration;
+
+    fn submission(part: u8, answer: &str, verdict: Verdict) -> Submission {
+        Submission {
+            part,
+            answer: answer.into(),
+            verdict,
+            timestamp: 0,
+            wait_until: None,
+        }
+    }
+
+    #[test]
+    fn creates_submission_from_outcome() {
+        let s = Submission::new(
+            1,
+            " 42\n",
+            SubmissionOutcome::TooHigh {
+                wait: Some(Duration::from_secs(60)),
+            },
+            1000,
+        );
+        assert_eq!(s.answer, "42");
+        assert_eq!(s.verdict, Verdict::TooHigh);
+        assert_eq!(s.wait_until, Some(1060));
+    }
+
+    #[test]
+    fn parses_log() {
+        let log = parse(concat!(
+            r#"{"part":1,"answer":"42","verdict":"too_low","timestamp":1,"wait_until":61}"#,
+            "\n",
+            r#"{"part":1,"answer":"43","verdict":"correct","timestamp":100,"wait_until":null}"#,
+            "\n"
+        ))
+        .unwrap();
+        assert_eq!(log.len(), 2);
+        assert_eq!(log[0].verdict, Verdict::TooLow);
+        assert_eq!(log[1].verdict, Verdict::Correct);
+    }
+
+    #[test]
+    fn refuses_placeholders() {
+        assert_eq!(check(&[], 1, "0"), Err(Refusal::Placeholder));
+        assert_eq!(check(&[], 1, " "), Err(Refusal::Placeholder));
+        assert_eq!(check(&[], 1, "10"), Ok(()));
+    }
+
+    #[test]
+    fn refuses_known_answers() {
+        let log = vec![
+            submission(1, "42", Verdict::Incorrect),
+            submission(2, "7", Verdict::Correct),
+        ];
+        assert_eq!(check(&log, 1, "42"), Err(Refusal::KnownWrong));
+        assert_eq!(check(&log, 1, "43"), Ok(()));
+        assert_eq!(check(&log, 2, "8"), Err(Refusal::AlreadySolved("7".into())));
+    }
+
+    #[test]
+    fn refuses_answers_outside_bounds() {
+        let log = vec![
+            submission(1, "100", Verdict::TooHigh),
+            submission(1, "200", Verdict::TooHigh),
+            submission(1, "10", Verdict::TooLow),
+            submission(2, "5", Verdict::TooHigh),
+        ];
+        assert_eq!(check(&log, 1, "150"), Err(Refusal::AboveUpperBound(100)));
+        assert_eq!(check(&log, 1, "250"), Err(Refusal::AboveUpperBound(100)));
+        assert_eq!(check(&log, 1, "3"), Err(Refusal::BelowLowerBound(10)));
+        assert_eq!(check(&log, 1, "50"), Ok(()));
+        assert_eq!(check(&log, 1, "ABC"), Ok(()));
+    }
+
+    #[test]
+    fn enforces_cooldown() {
+        assert_eq!(
+            check_cooldown(Some(160), 100),
+            Err(Refusal::Cooldown(Duration::from_secs(60)))
+        );
+        assert_eq!(check_cooldown(Some(160), 160), Ok(()));
+        assert_eq!(check_cooldown(None, 100), Ok(()));
+    }
+}
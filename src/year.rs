@@ -0,0 +1,238 @@
+use serde::{Deserialize, Serialize};
+use std::error::Error;
+use std::fmt::Display;
+use std::str::FromStr;
+
+use crate::Day;
+
+/// A year in which Advent of Code took place (i.e. 2015 or later).
+///
+/// # Display
+/// This value displays as a four digit number.
+///
+/// ```
+/// # use advent_of_code::Year;
+/// let year = Year::new(2023).unwrap();
+/// assert_eq!(year.to_string(), "2023")
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(try_from = "u16", into = "u16")]
+pub struct Year(u16);
+
+impl Year {
+    /// Creates a [`Year`] from the provided value if Advent of Code took place in it,
+    /// returns [`None`] otherwise.
+    pub fn new(year: u16) -> Option<Self> {
+        if year < 2015 {
+            return None;
+        }
+        Some(Self(year))
+    }
+
+    // Not part of the public API
+    #[doc(hidden)]
+    pub const fn __new_unchecked(year: u16) -> Self {
+        Self(year)
+    }
+
+    /// Converts the [`Year`] into an [`u16`].
+    pub fn into_inner(self) -> u16 {
+        self.0
+    }
+
+    /// The number of puzzles of the event: 25 until 2024, 12 from 2025 on.
+    pub const fn day_count(self) -> u8 {
+        if self.0 < 2025 {
+            25
+        } else {
+            12
+        }
+    }
+
+    /// The last day of the event.
+    pub fn last_day(self) -> Day {
+        Day::__new_unchecked(self.day_count())
+    }
+
+    /// An iterator that yields every day of the event.
+    pub fn days(self) -> impl Iterator<Item = Day> {
+        (1..=self.day_count()).map(Day::__new_unchecked)
+    }
+
+    /// Reads the default year from the `AOC_YEAR` environment variable.
+    pub fn from_env() -> Option<Self> {
+        std::env::var("AOC_YEAR").ok()?.parse().ok()
+    }
+}
+
+impl Display for Year {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:04}", self.0)
+    }
+}
+
+impl From<Year> for u16 {
+    fn from(year: Year) -> Self {
+        year.0
+    }
+}
+
+impl TryFrom<u16> for Year {
+    type Error = YearFromStrError;
+
+    fn try_from(year: u16) -> Result<Self, Self::Error> {
+        Self::new(year).ok_or(YearFromStrError)
+    }
+}
+
+impl FromStr for Year {
+    type Err = YearFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let year = s.parse().map_err(|_| YearFromStrError)?;
+        Self::new(year).ok_or(YearFromStrError)
+    }
+}
+
+/// An error which can be returned when parsing a [`Year`].
+#[derive(Debug)]
+pub struct YearFromStrError;
+
+impl Error for YearFromStrError {}
+
+impl Display for YearFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("expecting a year of 2015 or later")
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// A puzzle of a specific event, identified by [`Year`] and [`Day`].
+///
+/// # Display
+/// This value displays as `year-day`, which is also the name of its solution binary.
+///
+/// ```
+/// # use advent_of_code::{Day, PuzzleId, Year};
+/// let puzzle = PuzzleId::new(Year::new(2023).unwrap(), Day::new(8).unwrap()).unwrap();
+/// assert_eq!(puzzle.to_string(), "2023-08")
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct PuzzleId {
+    pub year: Year,
+    pub day: Day,
+}
+
+impl PuzzleId {
+    /// Creates a [`PuzzleId`] if the day is part of the event of the year,
+    /// returns [`None`] otherwise.
+    pub fn new(year: Year, day: Day) -> Option<Self> {
+        if day > year.day_count() {
+            return None;
+        }
+        Some(Self { year, day })
+    }
+
+    // Not part of the public API
+    #[doc(hidden)]
+    pub const fn __new_unchecked(year: Year, day: Day) -> Self {
+        Self { year, day }
+    }
+}
+
+impl Display for PuzzleId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}-{}", self.year, self.day)
+    }
+}
+
+impl FromStr for PuzzleId {
+    type Err = PuzzleIdFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (year, day) = s.split_once('-').ok_or(PuzzleIdFromStrError)?;
+        let year = year.parse().map_err(|_| PuzzleIdFromStrError)?;
+        let day = day.parse().map_err(|_| PuzzleIdFromStrError)?;
+        Self::new(year, day).ok_or(PuzzleIdFromStrError)
+    }
+}
+
+/// An error which can be returned when parsing a [`PuzzleId`].
+#[derive(Debug)]
+pub struct PuzzleIdFromStrError;
+
+impl Error for PuzzleIdFromStrError {}
+
+impl Display for PuzzleIdFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("expecting a puzzle in the format `year-day`, e.g. `2023-01`")
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Creates a [`Year`] value in a const context.
+#[macro_export]
+macro_rules! year {
+    ($year:expr) => {{
+        const _ASSERT: () = assert!(
+            $year >= 2015,
+            concat!("invalid year `", $year, "`, expecting 2015 or later"),
+        );
+        $crate::Year::__new_unchecked($year)
+    }};
+}
+
+/// Creates a [`PuzzleId`] value in a const context.
+#[macro_export]
+macro_rules! puzzle {
+    ($year:expr, $day:expr) => {{
+        const _ASSERT: () = assert!(
+            $day <= $crate::year!($year).day_count(),
+            concat!(
+                "invalid day number `",
+                $day,
+                "`, the event of ",
+                $year,
+                " has fewer days"
+            ),
+        );
+        $crate::PuzzleId::__new_unchecked($crate::year!($year), $crate::day!($day))
+    }};
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{PuzzleId, Year};
+    use crate::day;
+
+    #[test]
+    fn year_days() {
+        assert_eq!(Year(2023).days().count(), 25);
+        assert_eq!(Year(2023).last_day(), day!(25));
+        assert_eq!(Year(2025).days().count(), 12);
+        assert_eq!(Year(2025).last_day(), day!(12));
+    }
+
+    #[test]
+    fn parses_year() {
+        assert_eq!("2015".parse::<Year>().unwrap(), Year(2015));
+        assert!("2014".parse::<Year>().is_err());
+        assert!("foo".parse::<Year>().is_err());
+    }
+
+    #[test]
+    fn puzzle_id() {
+        assert_eq!(PuzzleId::new(Year(2023), day!(25)), Some(puzzle!(2023, 25)));
+        assert_eq!(PuzzleId::new(Year(2025), day!(13)), None);
+        assert_eq!(puzzle!(2022, 3).to_string(), "2022-03");
+        assert_eq!("2022-03".parse::<PuzzleId>().unwrap(), puzzle!(2022, 3));
+        assert!("2025-13".parse::<PuzzleId>().is_err());
+        assert!("03".parse::<PuzzleId>().is_err());
+    }
+}
+
+/* -------------------------------------------------------------------------- */